@@ -0,0 +1,220 @@
+//! RPC interface for the proof-of-existence pallet.
+
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpsee::{
+	core::{Error as JsonRpseeError, RpcResult},
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_poe_runtime_api::{
+	Attestation, ClaimDetails, ClaimMetadata, CustodyRecord, PoeApi as PoeRuntimeApi, Revocation,
+};
+
+/// Proof-of-existence RPC methods.
+#[rpc(client, server)]
+pub trait PoeApi<BlockHash, AccountId, BlockNumber, Hash> {
+	/// The details of `claim`, if it is registered.
+	#[method(name = "poe_getClaim")]
+	fn get_claim(
+		&self,
+		claim: Hash,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<ClaimDetails<AccountId, BlockNumber>>>;
+
+	/// What is kept of `claim` if it was revoked and not registered again since.
+	#[method(name = "poe_getRevocation")]
+	fn get_revocation(
+		&self,
+		claim: Hash,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<Revocation<AccountId, BlockNumber>>>;
+
+	/// Up to `limit` claims owned by `owner`, starting after the claim `start_after`.
+	///
+	/// The runtime may return fewer than `limit` claims per page, so only an empty page means
+	/// there are no more.
+	#[method(name = "poe_claimsByOwner")]
+	fn claims_by_owner(
+		&self,
+		owner: AccountId,
+		start_after: Option<Hash>,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<Hash>>;
+
+	/// The number of claims owned by `owner`.
+	#[method(name = "poe_claimCount")]
+	fn claim_count(&self, owner: AccountId, at: Option<BlockHash>) -> RpcResult<u32>;
+
+	/// The chain of owners of `claim`, oldest first.
+	#[method(name = "poe_claimHistory")]
+	fn claim_history(
+		&self,
+		claim: Hash,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<CustodyRecord<AccountId, BlockNumber>>>;
+
+	/// The revisions of `claim`, oldest first, including the claim itself.
+	#[method(name = "poe_claimRevisions")]
+	fn claim_revisions(&self, claim: Hash, at: Option<BlockHash>) -> RpcResult<Vec<Hash>>;
+
+	/// The attestations made of `claim`, oldest first.
+	#[method(name = "poe_claimAttestations")]
+	fn claim_attestations(
+		&self,
+		claim: Hash,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<Attestation<AccountId, BlockNumber>>>;
+
+	/// Whether the document digest `leaf` is included in the batch anchored at `root`, given the
+	/// sibling of each node on the path from `leaf` up to `root`.
+	#[method(name = "poe_verifyInclusion")]
+	fn verify_inclusion(
+		&self,
+		root: Hash,
+		leaf: Hash,
+		proof: Vec<Hash>,
+		at: Option<BlockHash>,
+	) -> RpcResult<bool>;
+}
+
+/// Provides RPC methods to query the proof-of-existence pallet.
+pub struct Poe<C, Block> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<Block>,
+}
+
+impl<C, Block> Poe<C, Block> {
+	/// Creates a new instance of the proof-of-existence RPC handler.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+fn runtime_error(message: &str, e: sp_api::ApiError) -> JsonRpseeError {
+	CallError::Custom(ErrorObject::owned(Error::RuntimeError.into(), message, Some(e.to_string())))
+		.into()
+}
+
+impl<C, Block, AccountId, BlockNumber, Hash>
+	PoeApiServer<<Block as BlockT>::Hash, AccountId, BlockNumber, Hash> for Poe<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: PoeRuntimeApi<Block, AccountId, BlockNumber, Hash>,
+	AccountId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	BlockNumber: Codec + Serialize + Send + Sync + 'static,
+	Hash: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+	fn get_claim(
+		&self,
+		claim: Hash,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<ClaimDetails<AccountId, BlockNumber>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.get_claim(at, claim).map_err(|e| runtime_error("Unable to query claim.", e))
+	}
+
+	fn get_revocation(
+		&self,
+		claim: Hash,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<Revocation<AccountId, BlockNumber>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.get_revocation(at, claim)
+			.map_err(|e| runtime_error("Unable to query claim revocation.", e))
+	}
+
+	fn claims_by_owner(
+		&self,
+		owner: AccountId,
+		start_after: Option<Hash>,
+		limit: u32,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<Hash>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.claims_by_owner(at, owner, start_after, limit)
+			.map_err(|e| runtime_error("Unable to query claims by owner.", e))
+	}
+
+	fn claim_count(&self, owner: AccountId, at: Option<Block::Hash>) -> RpcResult<u32> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.claim_count(at, owner)
+			.map_err(|e| runtime_error("Unable to query claim count.", e))
+	}
+
+	fn claim_history(
+		&self,
+		claim: Hash,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<CustodyRecord<AccountId, BlockNumber>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.claim_history(at, claim)
+			.map_err(|e| runtime_error("Unable to query claim history.", e))
+	}
+
+	fn claim_revisions(&self, claim: Hash, at: Option<Block::Hash>) -> RpcResult<Vec<Hash>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.claim_revisions(at, claim)
+			.map_err(|e| runtime_error("Unable to query claim revisions.", e))
+	}
+
+	fn claim_attestations(
+		&self,
+		claim: Hash,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<Attestation<AccountId, BlockNumber>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.claim_attestations(at, claim)
+			.map_err(|e| runtime_error("Unable to query claim attestations.", e))
+	}
+
+	fn verify_inclusion(
+		&self,
+		root: Hash,
+		leaf: Hash,
+		proof: Vec<Hash>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<bool> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.verify_inclusion(at, root, leaf, proof)
+			.map_err(|e| runtime_error("Unable to verify inclusion.", e))
+	}
+}
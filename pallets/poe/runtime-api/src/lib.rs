@@ -0,0 +1,46 @@
+//! Runtime API definition for the proof-of-existence pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+pub use pallet_poe::{Attestation, ClaimDetails, ClaimMetadata, CustodyRecord, Revocation};
+
+sp_api::decl_runtime_apis! {
+	/// Queries over the claims registered with the proof-of-existence pallet.
+	pub trait PoeApi<AccountId, BlockNumber, Hash> where
+		AccountId: Codec,
+		BlockNumber: Codec,
+		Hash: Codec,
+	{
+		/// The details of `claim`, if it is registered.
+		fn get_claim(claim: Hash) -> Option<ClaimDetails<AccountId, BlockNumber>>;
+
+		/// What is kept of `claim` if it was revoked and not registered again since.
+		fn get_revocation(claim: Hash) -> Option<Revocation<AccountId, BlockNumber>>;
+
+		/// Up to `limit` claims owned by `owner`, starting after the claim `start_after`.
+		///
+		/// Pass the last claim of a page as `start_after` to fetch the next page. The runtime may
+		/// cap the size of a page below `limit`, so only an empty page means there are no more.
+		fn claims_by_owner(owner: AccountId, start_after: Option<Hash>, limit: u32) -> Vec<Hash>;
+
+		/// The number of claims owned by `owner`.
+		fn claim_count(owner: AccountId) -> u32;
+
+		/// The chain of owners of `claim`, oldest first.
+		fn claim_history(claim: Hash) -> Vec<CustodyRecord<AccountId, BlockNumber>>;
+
+		/// The revisions of `claim`, oldest first, including the claim itself, or none if it is
+		/// not registered.
+		fn claim_revisions(claim: Hash) -> Vec<Hash>;
+
+		/// The attestations made of `claim`, oldest first.
+		fn claim_attestations(claim: Hash) -> Vec<Attestation<AccountId, BlockNumber>>;
+
+		/// Whether `leaf` is included in the registered batch `root`, given the sibling of each
+		/// node on the path from `leaf` up to `root`.
+		fn verify_inclusion(root: Hash, leaf: Hash, proof: Vec<Hash>) -> bool;
+	}
+}
@@ -0,0 +1,565 @@
+//! Benchmarking setup for pallet-poe
+//!
+//! The calls are made through `T::ClaimOrigin::try_successful_origin`. It yields a single
+//! origin, so the claims, offers, proposals and attestations of any other account are set up
+//! through the `ProofOfExistence` trait or in storage directly.
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as Poe;
+use frame_benchmarking::v1::{account, benchmarks_instance_pallet, BenchmarkError};
+use frame_support::{
+	sp_runtime::traits::{Bounded, Hash as HashT},
+	traits::{Currency, EnsureOrigin, Hooks, NamedReservableCurrency},
+	BoundedVec,
+};
+use sp_std::{vec, vec::Vec};
+
+// Give `who` enough funds to cover any claim deposit.
+fn fund<T: Config<I>, I: 'static>(who: &T::AccountId) {
+	T::Currency::make_free_balance_be(who, BalanceOf::<T, I>::max_value() / 2u32.into());
+}
+
+// The origin the calls are made with and the account it acts for, funded.
+fn claim_caller<T: Config<I>, I: 'static>(
+) -> Result<(T::RuntimeOrigin, T::AccountId), BenchmarkError> {
+	let origin = T::ClaimOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	let caller =
+		T::ClaimOrigin::ensure_origin(origin.clone()).map_err(|_| BenchmarkError::Weightless)?;
+	fund::<T, I>(&caller);
+	Ok((origin, caller))
+}
+
+// Register `claim` for `owner`, another account than the caller.
+fn create_for<T: Config<I>, I: 'static>(
+	owner: &T::AccountId,
+	claim: T::Hash,
+) -> Result<(), BenchmarkError> {
+	fund::<T, I>(owner);
+	<Poe<T, I> as ProofOfExistence<_, _>>::do_create(owner, claim)?;
+	Ok(())
+}
+
+// Metadata with every part at its limit.
+fn max_metadata<T: Config<I>, I: 'static>() -> ClaimMetadata {
+	let attribute = (
+		vec![0u8; T::MaxAttributeKeyLength::get() as usize],
+		vec![0u8; T::MaxAttributeValueLength::get() as usize],
+	);
+	ClaimMetadata {
+		mime_type: vec![0u8; T::MaxMimeTypeLength::get() as usize],
+		title: vec![0u8; T::MaxTitleLength::get() as usize],
+		uri: vec![0u8; T::MaxUriLength::get() as usize],
+		attributes: vec![attribute; T::MaxAttributes::get() as usize],
+	}
+}
+
+// Register a claim of the caller, with metadata, shared with `MaxCoOwners - 1` other accounts
+// of which `threshold` must approve proposals. Returns the claim and the other co-owners.
+fn co_owned_claim<T: Config<I>, I: 'static>(
+	origin: &T::RuntimeOrigin,
+	caller: &T::AccountId,
+	threshold: u32,
+) -> Result<(T::Hash, Vec<T::AccountId>), BenchmarkError> {
+	let claim = vec![0u8; 32];
+	Poe::<T, I>::create_claim(origin.clone(), claim.clone(), None, Some(max_metadata::<T, I>()))?;
+	let claim = T::ClaimHasher::hash(&claim);
+	let others = (1..T::MaxCoOwners::get())
+		.map(|i| account("co-owner", i, 0))
+		.collect::<Vec<_>>();
+	let mut owners = others.clone();
+	owners.push(caller.clone());
+	Poe::<T, I>::set_co_owners(origin.clone(), claim, owners, threshold)?;
+	Ok((claim, others))
+}
+
+// Have `count` other accounts attest to `claim`, each leaving a note at its limit.
+fn attest<T: Config<I>, I: 'static>(claim: T::Hash, count: u32) -> Result<(), BenchmarkError> {
+	let note = BoundedVec::truncate_from(vec![0u8; T::MaxNoteLength::get() as usize]);
+	let attested_at = frame_system::Pallet::<T>::block_number();
+	let deposit = T::AttestationDeposit::get();
+	for i in 0..count {
+		let attester: T::AccountId = account("attester", i, 0);
+		fund::<T, I>(&attester);
+		T::Currency::reserve_named(&T::ReserveId::get(), &attester, deposit)?;
+		Attestations::<T, I>::try_append(
+			&claim,
+			AttestationInfo { attester, attested_at, note: Some(note.clone()), deposit },
+		)
+		.map_err(|_| BenchmarkError::Stop("too many attestations"))?;
+	}
+	Ok(())
+}
+
+// Register a claim of another account than the caller and have `count` accounts attest to it.
+fn attested_claim<T: Config<I>, I: 'static>(count: u32) -> Result<T::Hash, BenchmarkError> {
+	let claim = T::Hash::default();
+	create_for::<T, I>(&account("owner", 0, 0), claim)?;
+	attest::<T, I>(claim, count)?;
+	Ok(claim)
+}
+
+benchmarks_instance_pallet! {
+	create_claim {
+		let l in 1 .. T::MaxClaimLength::get();
+		let (origin, _) = claim_caller::<T, I>()?;
+		let claim = vec![0u8; l as usize];
+		let expires_at = frame_system::Pallet::<T>::block_number() + 100u32.into();
+	}: _<T::RuntimeOrigin>(origin, claim.clone(), Some(expires_at), None)
+	verify {
+		let claim = T::ClaimHasher::hash(&claim);
+		assert_eq!(Proofs::<T, I>::get(&claim).and_then(|info| info.expires_at), Some(expires_at));
+	}
+
+	revoke_claim {
+		let a in 0 .. T::MaxAttestations::get();
+		let (origin, _) = claim_caller::<T, I>()?;
+		let claim = T::Hash::default();
+		Poe::<T, I>::create_hashed_claim(origin.clone(), claim, Some(HashAlgorithm::Sha2_256))?;
+		attest::<T, I>(claim, a)?;
+		let reason = vec![0u8; T::MaxReasonLength::get() as usize];
+	}: _<T::RuntimeOrigin>(origin, claim, Some(reason))
+	verify {
+		assert!(!Proofs::<T, I>::contains_key(&claim));
+		assert!(Tombstones::<T, I>::contains_key(&claim));
+	}
+
+	transfer_claim {
+		let (origin, _) = claim_caller::<T, I>()?;
+		let dest: T::AccountId = account("dest", 0, 0);
+		fund::<T, I>(&dest);
+		let claim = T::Hash::default();
+		Poe::<T, I>::create_hashed_claim(origin.clone(), claim, None)?;
+	}: _<T::RuntimeOrigin>(origin, claim, dest.clone())
+	verify {
+		assert_eq!(Proofs::<T, I>::get(&claim).map(|info| info.owner), Some(dest));
+	}
+
+	create_hashed_claim {
+		let (origin, _) = claim_caller::<T, I>()?;
+		let claim = T::Hash::default();
+	}: _<T::RuntimeOrigin>(origin, claim, Some(HashAlgorithm::Sha2_256))
+	verify {
+		assert_eq!(
+			Proofs::<T, I>::get(&claim).and_then(|info| info.algorithm),
+			Some(HashAlgorithm::Sha2_256)
+		);
+	}
+
+	offer_claim {
+		let (origin, _) = claim_caller::<T, I>()?;
+		let dest: T::AccountId = account("dest", 0, 0);
+		let claim = T::Hash::default();
+		Poe::<T, I>::create_hashed_claim(origin.clone(), claim, None)?;
+	}: _<T::RuntimeOrigin>(origin, claim, dest.clone())
+	verify {
+		assert_eq!(PendingTransfers::<T, I>::get(&claim).map(|offer| offer.dest), Some(dest));
+	}
+
+	accept_claim {
+		let (origin, caller) = claim_caller::<T, I>()?;
+		let claim = T::Hash::default();
+		create_for::<T, I>(&account("owner", 0, 0), claim)?;
+		let expires_at = frame_system::Pallet::<T>::block_number() + T::OfferDuration::get();
+		PendingTransfers::<T, I>::insert(
+			&claim,
+			PendingTransfer { dest: caller.clone(), expires_at },
+		);
+	}: _<T::RuntimeOrigin>(origin, claim)
+	verify {
+		assert_eq!(Proofs::<T, I>::get(&claim).map(|info| info.owner), Some(caller));
+		assert!(!PendingTransfers::<T, I>::contains_key(&claim));
+	}
+
+	cancel_offer {
+		let (origin, _) = claim_caller::<T, I>()?;
+		let dest: T::AccountId = account("dest", 0, 0);
+		let claim = T::Hash::default();
+		Poe::<T, I>::create_hashed_claim(origin.clone(), claim, None)?;
+		Poe::<T, I>::offer_claim(origin.clone(), claim, dest)?;
+	}: _<T::RuntimeOrigin>(origin, claim)
+	verify {
+		assert!(!PendingTransfers::<T, I>::contains_key(&claim));
+	}
+
+	create_batch_root {
+		let (origin, _) = claim_caller::<T, I>()?;
+		let root = T::Hash::default();
+	}: _<T::RuntimeOrigin>(origin, root, 1_000)
+	verify {
+		assert_eq!(BatchRoots::<T, I>::get(&root), Some(1_000));
+	}
+
+	renew_claim {
+		let (origin, _) = claim_caller::<T, I>()?;
+		let claim = vec![0u8; 32];
+		let expires_at = frame_system::Pallet::<T>::block_number() + 100u32.into();
+		Poe::<T, I>::create_claim(origin.clone(), claim.clone(), Some(expires_at), None)?;
+		let claim = T::ClaimHasher::hash(&claim);
+		let renewed = expires_at + 100u32.into();
+	}: _<T::RuntimeOrigin>(origin, claim, renewed)
+	verify {
+		assert_eq!(Proofs::<T, I>::get(&claim).and_then(|info| info.expires_at), Some(renewed));
+	}
+
+	expire_claims {
+		let n in 0 .. T::MaxExpiriesPerBlock::get();
+		let now = frame_system::Pallet::<T>::block_number();
+		let expires_at = now + 1u32.into();
+		ExpiryCursor::<T, I>::put(now);
+		for i in 0 .. n {
+			// Each claim has an owner of its own, and is scheduled to expire in storage.
+			let claim = T::ClaimHasher::hash(&i.encode());
+			create_for::<T, I>(&account("owner", i, 0), claim)?;
+			Proofs::<T, I>::mutate(&claim, |info| {
+				if let Some(info) = info {
+					info.expires_at = Some(expires_at);
+				}
+			});
+			ExpiryQueue::<T, I>::insert(expires_at, &claim, ());
+			attest::<T, I>(claim, T::MaxAttestations::get())?;
+		}
+	}: {
+		Poe::<T, I>::on_initialize(expires_at);
+	}
+	verify {
+		assert_eq!(ExpiryQueue::<T, I>::iter_key_prefix(expires_at).count(), 0);
+		assert_eq!(ExpiryCursor::<T, I>::get(), Some(expires_at + 1u32.into()));
+	}
+
+	force_revoke {
+		let a in 0 .. T::MaxAttestations::get();
+		let origin =
+			T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let (owner, _) = claim_caller::<T, I>()?;
+		let claim = vec![0u8; 32];
+		let expires_at = frame_system::Pallet::<T>::block_number() + 100u32.into();
+		Poe::<T, I>::create_claim(owner, claim.clone(), Some(expires_at), None)?;
+		let claim = T::ClaimHasher::hash(&claim);
+		attest::<T, I>(claim, a)?;
+		Poe::<T, I>::freeze_claim(origin.clone(), claim)?;
+		let reason = vec![0u8; T::MaxReasonLength::get() as usize];
+	}: _<T::RuntimeOrigin>(origin, claim, Some(reason))
+	verify {
+		assert!(!Proofs::<T, I>::contains_key(&claim));
+		assert!(Tombstones::<T, I>::contains_key(&claim));
+	}
+
+	force_transfer {
+		let origin =
+			T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let dest: T::AccountId = account("dest", 0, 0);
+		fund::<T, I>(&dest);
+		let claim = T::Hash::default();
+		create_for::<T, I>(&account("owner", 0, 0), claim)?;
+	}: _<T::RuntimeOrigin>(origin, claim, dest.clone())
+	verify {
+		assert_eq!(Proofs::<T, I>::get(&claim).map(|info| info.owner), Some(dest));
+	}
+
+	freeze_claim {
+		let origin =
+			T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let claim = T::Hash::default();
+		create_for::<T, I>(&account("owner", 0, 0), claim)?;
+	}: _<T::RuntimeOrigin>(origin, claim)
+	verify {
+		assert_eq!(Proofs::<T, I>::get(&claim).map(|info| info.frozen), Some(true));
+	}
+
+	unfreeze_claim {
+		let origin =
+			T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let claim = T::Hash::default();
+		create_for::<T, I>(&account("owner", 0, 0), claim)?;
+		Poe::<T, I>::freeze_claim(origin.clone(), claim)?;
+	}: _<T::RuntimeOrigin>(origin, claim)
+	verify {
+		assert_eq!(Proofs::<T, I>::get(&claim).map(|info| info.frozen), Some(false));
+	}
+
+	set_claim_metadata {
+		let (origin, _) = claim_caller::<T, I>()?;
+		let claim = vec![0u8; 32];
+		Poe::<T, I>::create_claim(
+			origin.clone(),
+			claim.clone(),
+			None,
+			Some(ClaimMetadata::default()),
+		)?;
+		let claim = T::ClaimHasher::hash(&claim);
+		let metadata = max_metadata::<T, I>();
+	}: _<T::RuntimeOrigin>(origin, claim, metadata.clone())
+	verify {
+		assert_eq!(ClaimMetadataOf::<T, I>::get(&claim).map(Into::into), Some(metadata));
+	}
+
+	clear_claim_metadata {
+		let (origin, _) = claim_caller::<T, I>()?;
+		let claim = vec![0u8; 32];
+		Poe::<T, I>::create_claim(
+			origin.clone(),
+			claim.clone(),
+			None,
+			Some(max_metadata::<T, I>()),
+		)?;
+		let claim = T::ClaimHasher::hash(&claim);
+	}: _<T::RuntimeOrigin>(origin, claim)
+	verify {
+		assert!(!ClaimMetadataOf::<T, I>::contains_key(&claim));
+	}
+
+	commit_claim {
+		let (origin, caller) = claim_caller::<T, I>()?;
+		let commitment = T::Hash::default();
+	}: _<T::RuntimeOrigin>(origin, commitment)
+	verify {
+		assert_eq!(Commitments::<T, I>::get(&commitment).map(|record| record.who), Some(caller));
+	}
+
+	reveal_claim {
+		let l in 1 .. T::MaxClaimLength::get();
+		let a in 0 .. T::MaxAttestations::get();
+		let (origin, caller) = claim_caller::<T, I>()?;
+		let claim = vec![0u8; l as usize];
+		let salt = [1u8; 32];
+		let digest = T::ClaimHasher::hash(&claim);
+		Poe::<T, I>::commit_claim(
+			origin.clone(),
+			Poe::<T, I>::commitment_of(&digest, &salt, &caller),
+		)?;
+		// The worst case displaces a claim, with metadata, registered by another account after
+		// the commitment. The caller registers it and hands it over, metadata and all.
+		let now = frame_system::Pallet::<T>::block_number();
+		frame_system::Pallet::<T>::set_block_number(now + 1u32.into());
+		let other: T::AccountId = account("other", 0, 0);
+		fund::<T, I>(&other);
+		Poe::<T, I>::create_claim(
+			origin.clone(),
+			claim.clone(),
+			None,
+			Some(max_metadata::<T, I>()),
+		)?;
+		<Poe<T, I> as ProofOfExistence<_, _>>::do_transfer(&caller, &digest, &other)?;
+		attest::<T, I>(digest, a)?;
+		frame_system::Pallet::<T>::set_block_number(now + 1u32.into() + T::MinRevealDelay::get());
+	}: _<T::RuntimeOrigin>(origin, claim, salt)
+	verify {
+		assert_eq!(Proofs::<T, I>::get(&digest).map(|info| info.owner), Some(caller));
+	}
+
+	remove_commitment {
+		let (origin, _) = claim_caller::<T, I>()?;
+		// The worst case removes the expired commitment of another account.
+		let committer: T::AccountId = account("committer", 0, 0);
+		fund::<T, I>(&committer);
+		let commitment = T::Hash::default();
+		let deposit = Poe::<T, I>::claim_deposit();
+		T::Currency::reserve_named(&T::ReserveId::get(), &committer, deposit)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		Commitments::<T, I>::insert(
+			&commitment,
+			Commitment { who: committer, committed_at: now, deposit },
+		);
+		frame_system::Pallet::<T>::set_block_number(
+			now + T::CommitmentLifetime::get() + 1u32.into(),
+		);
+	}: _<T::RuntimeOrigin>(origin, commitment)
+	verify {
+		assert!(!Commitments::<T, I>::contains_key(&commitment));
+	}
+
+	set_co_owners {
+		let n in 1 .. T::MaxCoOwners::get();
+		let (origin, caller) = claim_caller::<T, I>()?;
+		let claim = T::Hash::default();
+		Poe::<T, I>::create_hashed_claim(origin.clone(), claim, None)?;
+		let mut owners = (1..n).map(|i| account("co-owner", i, 0)).collect::<Vec<T::AccountId>>();
+		owners.push(caller);
+	}: _<T::RuntimeOrigin>(origin, claim, owners, n)
+	verify {
+		assert_eq!(CoOwners::<T, I>::get(&claim).map(|co| co.threshold), Some(n));
+	}
+
+	propose_revoke {
+		let a in 0 .. T::MaxAttestations::get();
+		let (origin, caller) = claim_caller::<T, I>()?;
+		// A single approval is enough, so the claim is revoked straight away.
+		let (claim, _) = co_owned_claim::<T, I>(&origin, &caller, 1)?;
+		attest::<T, I>(claim, a)?;
+	}: _<T::RuntimeOrigin>(origin, claim)
+	verify {
+		assert!(!Proofs::<T, I>::contains_key(&claim));
+	}
+
+	propose_transfer {
+		let (origin, caller) = claim_caller::<T, I>()?;
+		let dest: T::AccountId = account("dest", 0, 0);
+		fund::<T, I>(&dest);
+		// A single approval is enough, so the claim is transferred straight away.
+		let (claim, _) = co_owned_claim::<T, I>(&origin, &caller, 1)?;
+	}: _<T::RuntimeOrigin>(origin, claim, dest.clone())
+	verify {
+		assert_eq!(Proofs::<T, I>::get(&claim).map(|info| info.owner), Some(dest));
+	}
+
+	approve_revoke_proposal {
+		let a in 0 .. T::MaxAttestations::get();
+		let (origin, caller) = claim_caller::<T, I>()?;
+		// Another co-owner proposed the revocation, which the approval of the caller carries.
+		let (claim, others) = co_owned_claim::<T, I>(&origin, &caller, 2)?;
+		attest::<T, I>(claim, a)?;
+		let expires_at = frame_system::Pallet::<T>::block_number() + T::ProposalDuration::get();
+		Proposals::<T, I>::insert(
+			&claim,
+			Proposal {
+				action: ProposalAction::Revoke,
+				approvals: BoundedVec::truncate_from(vec![others[0].clone()]),
+				expires_at,
+			},
+		);
+	}: approve_proposal<T::RuntimeOrigin>(origin, claim)
+	verify {
+		assert!(!Proofs::<T, I>::contains_key(&claim));
+		assert!(Tombstones::<T, I>::contains_key(&claim));
+	}
+
+	approve_transfer_proposal {
+		let (origin, caller) = claim_caller::<T, I>()?;
+		let dest: T::AccountId = account("dest", 0, 0);
+		fund::<T, I>(&dest);
+		// Another co-owner proposed the transfer, which the approval of the caller carries.
+		let (claim, others) = co_owned_claim::<T, I>(&origin, &caller, 2)?;
+		let expires_at = frame_system::Pallet::<T>::block_number() + T::ProposalDuration::get();
+		Proposals::<T, I>::insert(
+			&claim,
+			Proposal {
+				action: ProposalAction::Transfer(dest.clone()),
+				approvals: BoundedVec::truncate_from(vec![others[0].clone()]),
+				expires_at,
+			},
+		);
+	}: approve_proposal<T::RuntimeOrigin>(origin, claim)
+	verify {
+		assert_eq!(Proofs::<T, I>::get(&claim).map(|info| info.owner), Some(dest));
+	}
+
+	cancel_proposal {
+		let (origin, caller) = claim_caller::<T, I>()?;
+		let dest: T::AccountId = account("dest", 0, 0);
+		let (claim, _) = co_owned_claim::<T, I>(&origin, &caller, 2)?;
+		Poe::<T, I>::propose_transfer(origin.clone(), claim, dest)?;
+	}: _<T::RuntimeOrigin>(origin, claim)
+	verify {
+		assert!(!Proposals::<T, I>::contains_key(&claim));
+	}
+
+	attest_claim {
+		let (origin, _) = claim_caller::<T, I>()?;
+		let claim = attested_claim::<T, I>(T::MaxAttestations::get() - 1)?;
+		let note = vec![0u8; T::MaxNoteLength::get() as usize];
+	}: _<T::RuntimeOrigin>(origin, claim, Some(note))
+	verify {
+		assert_eq!(Attestations::<T, I>::get(&claim).len() as u32, T::MaxAttestations::get());
+	}
+
+	withdraw_attestation {
+		let (origin, _) = claim_caller::<T, I>()?;
+		// The attestation of the caller is the last one searched for.
+		let claim = attested_claim::<T, I>(T::MaxAttestations::get() - 1)?;
+		Poe::<T, I>::attest_claim(origin.clone(), claim, None)?;
+	}: _<T::RuntimeOrigin>(origin, claim)
+	verify {
+		assert_eq!(Attestations::<T, I>::get(&claim).len() as u32, T::MaxAttestations::get() - 1);
+	}
+
+	supersede_claim {
+		let (origin, _) = claim_caller::<T, I>()?;
+		// The worst case supersedes a claim that is itself a revision.
+		let first = T::ClaimHasher::hash(&[0]);
+		let old = T::ClaimHasher::hash(&[1]);
+		let new = T::ClaimHasher::hash(&[2]);
+		Poe::<T, I>::create_hashed_claim(origin.clone(), first, Some(HashAlgorithm::Sha2_256))?;
+		Poe::<T, I>::supersede_claim(origin.clone(), first, old)?;
+	}: _<T::RuntimeOrigin>(origin, old, new)
+	verify {
+		assert_eq!(Poe::<T, I>::revision_chain(new), vec![first, old, new]);
+	}
+
+	list_claim {
+		let (origin, _) = claim_caller::<T, I>()?;
+		let claim = T::Hash::default();
+		Poe::<T, I>::create_hashed_claim(origin.clone(), claim, None)?;
+		let price = T::Currency::minimum_balance();
+	}: _<T::RuntimeOrigin>(origin, claim, price)
+	verify {
+		assert_eq!(Listings::<T, I>::get(&claim), Some(price));
+	}
+
+	delist_claim {
+		let (origin, _) = claim_caller::<T, I>()?;
+		let claim = T::Hash::default();
+		Poe::<T, I>::create_hashed_claim(origin.clone(), claim, None)?;
+		let price = T::Currency::minimum_balance();
+		Poe::<T, I>::list_claim(origin.clone(), claim, price)?;
+	}: _<T::RuntimeOrigin>(origin, claim)
+	verify {
+		assert!(!Listings::<T, I>::contains_key(&claim));
+	}
+
+	buy_claim {
+		let (origin, caller) = claim_caller::<T, I>()?;
+		// The worst case pays a royalty to the creator and moves the metadata deposit as well.
+		// The caller registers the claim with its metadata and hands it over to the seller, and
+		// the creation is credited to a third account.
+		let creator: T::AccountId = account("creator", 0, 0);
+		fund::<T, I>(&creator);
+		let seller: T::AccountId = account("seller", 0, 0);
+		fund::<T, I>(&seller);
+		let claim = vec![0u8; 32];
+		Poe::<T, I>::create_claim(
+			origin.clone(),
+			claim.clone(),
+			None,
+			Some(max_metadata::<T, I>()),
+		)?;
+		let claim = T::ClaimHasher::hash(&claim);
+		<Poe<T, I> as ProofOfExistence<_, _>>::do_transfer(&caller, &claim, &seller)?;
+		Proofs::<T, I>::mutate(&claim, |info| {
+			if let Some(info) = info {
+				info.creator = creator;
+			}
+		});
+		let price = BalanceOf::<T, I>::max_value() / 100u32.into();
+		Listings::<T, I>::insert(&claim, price);
+	}: _<T::RuntimeOrigin>(origin, claim, price)
+	verify {
+		assert_eq!(Proofs::<T, I>::get(&claim).map(|info| info.owner), Some(caller));
+		assert!(!Listings::<T, I>::contains_key(&claim));
+	}
+
+	remove_attestation {
+		let (origin, _) = claim_caller::<T, I>()?;
+		let claim = T::Hash::default();
+		Poe::<T, I>::create_hashed_claim(origin.clone(), claim, None)?;
+		attest::<T, I>(claim, T::MaxAttestations::get())?;
+		// The last attestation is the last one searched for.
+		let attester: T::AccountId = account("attester", T::MaxAttestations::get() - 1, 0);
+	}: _<T::RuntimeOrigin>(origin, claim, attester)
+	verify {
+		assert_eq!(Attestations::<T, I>::get(&claim).len() as u32, T::MaxAttestations::get() - 1);
+	}
+
+	clear_tombstone {
+		let (origin, _) = claim_caller::<T, I>()?;
+		let claim = T::Hash::default();
+		Poe::<T, I>::create_hashed_claim(origin.clone(), claim, None)?;
+		Poe::<T, I>::revoke_claim(origin.clone(), claim, None)?;
+	}: _<T::RuntimeOrigin>(origin, claim)
+	verify {
+		assert!(!Tombstones::<T, I>::contains_key(&claim));
+	}
+
+	impl_benchmark_test_suite!(Poe, crate::mock::new_test_ext(), crate::mock::Test);
+}
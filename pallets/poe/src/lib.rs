@@ -8,134 +8,2544 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod migrations;
+pub mod weights;
+pub use weights::WeightInfo;
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{dispatch::DispatchResult, RuntimeDebug};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_std::vec::Vec;
+
+/// The algorithm used to compute a claim digest off-chain.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum HashAlgorithm {
+	Blake2_256,
+	Sha2_256,
+	Keccak256,
+}
+
+/// A transfer offered by the owner of a claim, awaiting acceptance by its recipient.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PendingTransfer<AccountId, BlockNumber> {
+	/// The account the claim is offered to.
+	pub dest: AccountId,
+	/// The last block at which the offer can be accepted.
+	pub expires_at: BlockNumber,
+}
+
+/// A claim committed to by an account, waiting to be revealed.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Commitment<AccountId, BlockNumber, Balance> {
+	/// The account that made the commitment, and owns the claim once it is revealed.
+	pub who: AccountId,
+	/// The block at which the commitment was made.
+	pub committed_at: BlockNumber,
+	/// The amount reserved from the account for the commitment.
+	pub deposit: Balance,
+}
+
+/// An action on a co-owned claim, taken once enough of its owners approve.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum ProposalAction<AccountId> {
+	/// Revoke the claim, returning its deposits.
+	Revoke,
+	/// Transfer the claim to the given account, which becomes its sole owner.
+	Transfer(AccountId),
+}
+
+/// How an owner came to hold a claim.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum CustodyAction {
+	/// The owner created the claim.
+	Created,
+	/// The claim was transferred to the owner.
+	Transferred,
+}
+
+/// A period during which an account owned a claim.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct CustodyRecord<AccountId, BlockNumber> {
+	/// The account owning the claim.
+	pub owner: AccountId,
+	/// The block at which the owner took custody.
+	pub from_block: BlockNumber,
+	/// The block at which the owner gave up custody, or `None` for the current owner.
+	pub to_block: Option<BlockNumber>,
+	/// How the owner came to hold the claim.
+	pub action: CustodyAction,
+}
+
+/// The links of a claim to the revisions it supersedes and is superseded by.
+#[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ClaimRevision<Hash> {
+	/// The claim this one supersedes, if any.
+	pub previous: Option<Hash>,
+	/// The claim superseding this one, if any.
+	pub next: Option<Hash>,
+}
+
+/// Everything recorded about a registered claim.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ClaimInfo<AccountId, BlockNumber, Balance> {
+	/// The account owning the claim.
+	pub owner: AccountId,
+	/// The account that created the claim.
+	pub creator: AccountId,
+	/// The block at which the claim was created.
+	pub created_at: BlockNumber,
+	/// The time at which the claim was created, in milliseconds since the Unix epoch. `None` for
+	/// claims registered at genesis or before times were recorded.
+	pub created_time: Option<u64>,
+	/// The block at which the claim last changed hands, if it ever did.
+	pub transferred_at: Option<BlockNumber>,
+	/// The time at which the claim last changed hands, in milliseconds since the Unix epoch, if
+	/// it was recorded.
+	pub transferred_time: Option<u64>,
+	/// The algorithm the digest was computed with, if it was given.
+	pub algorithm: Option<HashAlgorithm>,
+	/// The block at which the claim expires, if it does.
+	pub expires_at: Option<BlockNumber>,
+	/// Whether the claim is frozen, preventing its owner from transferring or revoking it.
+	pub frozen: bool,
+	/// The amount reserved from the owner for the claim.
+	pub deposit: Balance,
+}
+
+/// Descriptive metadata attached to a claim, telling what its digest refers to.
+#[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct ClaimMetadata {
+	/// The MIME type of the document, such as `application/pdf`.
+	pub mime_type: Vec<u8>,
+	/// A human-readable title for the document.
+	pub title: Vec<u8>,
+	/// Where the document can be found, such as an IPFS or HTTP URI.
+	pub uri: Vec<u8>,
+	/// Further properties of the document, as key/value pairs.
+	pub attributes: Vec<(Vec<u8>, Vec<u8>)>,
+}
+
+/// A statement by a third party that it has seen the document behind a claim.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct Attestation<AccountId, BlockNumber> {
+	/// The account attesting to the claim.
+	pub attester: AccountId,
+	/// The block at which the attestation was made.
+	pub attested_at: BlockNumber,
+	/// A note left by the attester, if any.
+	pub note: Option<Vec<u8>>,
+}
+
+/// What is kept of a revoked claim, as reported by the runtime API.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct Revocation<AccountId, BlockNumber> {
+	/// The account owning the claim when it was revoked.
+	pub owner: AccountId,
+	/// The account that revoked the claim, or `None` if it was revoked by `ForceOrigin`.
+	pub revoker: Option<AccountId>,
+	/// The block at which the claim was revoked.
+	pub revoked_at: BlockNumber,
+	/// The reason given for revoking the claim, if any.
+	pub reason: Option<Vec<u8>>,
+}
+
+/// A registered claim, as reported by the runtime API.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct ClaimDetails<AccountId, BlockNumber> {
+	/// The account owning the claim.
+	pub owner: AccountId,
+	/// The block at which the claim was created.
+	pub created_at: BlockNumber,
+	/// The time at which the claim was created, in milliseconds since the Unix epoch, if it was
+	/// recorded.
+	pub created_time: Option<u64>,
+	/// The block at which the claim last changed hands, if it ever did.
+	pub transferred_at: Option<BlockNumber>,
+	/// The time at which the claim last changed hands, in milliseconds since the Unix epoch, if
+	/// it was recorded.
+	pub transferred_time: Option<u64>,
+	/// The algorithm the digest was computed with, if it was given.
+	pub algorithm: Option<HashAlgorithm>,
+	/// The block at which the claim expires, if it does.
+	pub expires_at: Option<BlockNumber>,
+	/// Whether the claim is frozen, preventing its owner from transferring or revoking it.
+	pub frozen: bool,
+	/// The metadata attached to the claim, if any.
+	pub metadata: Option<ClaimMetadata>,
+	/// The accounts owning the claim together, or empty if it has a single owner.
+	pub co_owners: Vec<AccountId>,
+	/// The number of co-owners that must approve a proposal for it to take effect.
+	pub threshold: u32,
+	/// Whether a newer revision of the claim was registered with `supersede_claim`.
+	pub superseded: bool,
+}
+
+/// Claims as seen by other pallets, which can query and manage them through this trait rather
+/// than through the storage of the registry.
+///
+/// A call that fails leaves storage as it was, so callers need not run it in a transaction of
+/// their own.
+pub trait ProofOfExistence<AccountId, BlockNumber> {
+	/// The identifier of a claim.
+	type Claim;
+
+	/// The owner of `claim`, if it is registered.
+	fn owner_of(claim: &Self::Claim) -> Option<AccountId>;
+
+	/// Whether `claim` is registered.
+	fn exists(claim: &Self::Claim) -> bool;
+
+	/// The block at which `claim` was registered, if it is.
+	fn created_at(claim: &Self::Claim) -> Option<BlockNumber>;
+
+	/// Register `claim` for `who`, reserving its deposit.
+	fn do_create(who: &AccountId, claim: Self::Claim) -> DispatchResult;
+
+	/// Revoke `claim` on behalf of `who`, who must own it alone and while it is not frozen.
+	fn do_revoke(who: &AccountId, claim: &Self::Claim) -> DispatchResult;
+
+	/// Transfer `claim` from `from`, who must own it alone and while it is not frozen, to `to`.
+	fn do_transfer(from: &AccountId, claim: &Self::Claim, to: &AccountId) -> DispatchResult;
+}
+
 // Define the pallet module using the frame_support::pallet macro
 // A pallet for proof of existence.
 #[frame_support::pallet]
 pub mod pallet {
-    use frame_support::pallet_prelude::*;
-    use frame_system::pallet_prelude::*;
-    use sp_std::prelude::*;
-
-    // Define the pallet struct using the pallet::pallet macro
-    #[pallet::pallet]
-    pub struct Pallet<T>(_);
-
-    // Define the pallet's configuration trait
-    #[pallet::config]
-    pub trait Config: frame_system::Config {
-        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
-        #[pallet::constant]
-        type MaxClaimLength: Get<u32>;
-    }
-
-    // Define the storage item for storing the claims
-    #[pallet::storage]
-    #[pallet::getter(fn something)]
-    pub type Proofs<T: Config> = StorageMap<
-        _,
-        Blake2_128Concat,
-        BoundedVec<u8, T::MaxClaimLength>,
-        (T::AccountId, T::BlockNumber)
-    >;
-
-    // Define the events that can be emitted by the pallet
-    #[pallet::event]
-    #[pallet::generate_deposit(pub(super) fn deposit_event)]
-    pub enum Event<T: Config> {
-        ClaimCreated(T::AccountId, Vec<u8>),
-        ClaimRevoked(T::AccountId, Vec<u8>),
-        ClaimTransfered(T::AccountId, T::AccountId, Vec<u8>)
-    }
-
-    // Define the errors that can be returned by the pallet
-    #[pallet::error]
-    pub enum Error<T> {
-        ProofAlreadyExists,
-        ClaimTooLong,
-        ClaimNotExists,
-        NotClaimOwner,
-    }
-
-    // Define the pallet's dispatchable functions
-    #[pallet::call]
-    impl<T: Config> Pallet<T> {
-        // Create a new claim
-		#[pallet::call_index(0)]
-        #[pallet::weight(0)]
-        pub fn create_claim(origin: OriginFor<T>, claim: Vec<u8>) -> DispatchResult {
-            // Verify that the transaction is signed by a valid account
-            let who = ensure_signed(origin)?;
-            // Create a bounded vector from the claim data
-            let bounded_claim = BoundedVec::<u8, T::MaxClaimLength>::try_from(claim.clone()).map_err(
-                |_| Error::<T>::ClaimTooLong
-            )?;
-            // Ensure that the claim does not already exist
-            ensure!(!Proofs::<T>::contains_key(&bounded_claim), Error::<T>::ProofAlreadyExists);
-            // Store the claim along with the owner's account ID and the current block number
-            Proofs::<T>::insert(
-                &bounded_claim,
-                (who.clone(), frame_system::Pallet::<T>::block_number())
-            );
-            // Emit the ClaimCreated event
-            Self::deposit_event(Event::ClaimCreated(who, claim));
-            Ok(())
-        }
-
-        // Revoke an existing claim
-		#[pallet::call_index(1)]
-        #[pallet::weight(0)]
-        pub fn revoke_claim(origin: OriginFor<T>, claim: Vec<u8>) -> DispatchResult {
-            // Verify that the transaction is signed by a valid account
-            let sender = ensure_signed(origin)?;
-
-			// Ensure that the claim is not too long.
-			let bounded_claim = BoundedVec::<u8, T::MaxClaimLength>::try_from(claim.clone()).map_err(
-				|_| Error::<T>::ClaimTooLong
-			)?;
+	use frame_support::{
+		pallet_prelude::*,
+		sp_runtime::{
+			traits::{Hash as HashT, Saturating, Zero},
+			Permill,
+		},
+		storage::with_storage_layer,
+		traits::{
+			BalanceStatus, Currency, ExistenceRequirement, NamedReservableCurrency, UnixTime,
+		},
+	};
+	use frame_system::pallet_prelude::*;
+	#[cfg(any(feature = "try-runtime", test))]
+	use sp_std::collections::btree_map::BTreeMap;
+	use sp_std::{marker::PhantomData, prelude::*};
+
+	use crate::{
+		Attestation, ClaimDetails, ClaimInfo, ClaimMetadata, ClaimRevision, Commitment,
+		CustodyAction, CustodyRecord, HashAlgorithm, PendingTransfer, ProofOfExistence,
+		ProposalAction, Revocation, WeightInfo,
+	};
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+
+	/// The byte a leaf of a merkle batch is prefixed with before hashing.
+	const MERKLE_LEAF_PREFIX: u8 = 0x00;
+	/// The byte the children of a node of a merkle batch are prefixed with before hashing.
+	const MERKLE_NODE_PREFIX: u8 = 0x01;
+
+	/// The balance type of the currency used for claim deposits.
+	pub type BalanceOf<T, I = ()> =
+		<<T as Config<I>>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+	/// The record kept for each claim of this pallet.
+	pub type ClaimInfoOf<T, I = ()> = ClaimInfo<
+		<T as frame_system::Config>::AccountId,
+		<T as frame_system::Config>::BlockNumber,
+		BalanceOf<T, I>,
+	>;
+
+	/// The metadata attached to a claim, with each part bounded by its limit in [`Config`].
+	#[derive(
+		CloneNoBound,
+		Encode,
+		Decode,
+		EqNoBound,
+		PartialEqNoBound,
+		RuntimeDebugNoBound,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T, I))]
+	#[codec(mel_bound())]
+	pub struct MetadataInfo<T: Config<I>, I: 'static = ()> {
+		/// The MIME type of the document.
+		pub mime_type: BoundedVec<u8, T::MaxMimeTypeLength>,
+		/// A human-readable title for the document.
+		pub title: BoundedVec<u8, T::MaxTitleLength>,
+		/// Where the document can be found.
+		pub uri: BoundedVec<u8, T::MaxUriLength>,
+		/// Further properties of the document, as key/value pairs.
+		pub attributes: BoundedVec<
+			(BoundedVec<u8, T::MaxAttributeKeyLength>, BoundedVec<u8, T::MaxAttributeValueLength>),
+			T::MaxAttributes,
+		>,
+		/// The amount reserved from the owner of the claim for its metadata.
+		pub deposit: BalanceOf<T, I>,
+	}
+
+	impl<T: Config<I>, I: 'static> From<MetadataInfo<T, I>> for ClaimMetadata {
+		fn from(info: MetadataInfo<T, I>) -> Self {
+			ClaimMetadata {
+				mime_type: info.mime_type.into_inner(),
+				title: info.title.into_inner(),
+				uri: info.uri.into_inner(),
+				attributes: info
+					.attributes
+					.into_iter()
+					.map(|(key, value)| (key.into_inner(), value.into_inner()))
+					.collect(),
+			}
+		}
+	}
+
+	/// The accounts owning a claim together.
+	#[derive(
+		CloneNoBound,
+		Encode,
+		Decode,
+		EqNoBound,
+		PartialEqNoBound,
+		RuntimeDebugNoBound,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T, I))]
+	#[codec(mel_bound())]
+	pub struct CoOwnership<T: Config<I>, I: 'static = ()> {
+		/// The co-owners, including the owner recorded in `Proofs`, who holds the deposits.
+		pub owners: BoundedVec<T::AccountId, T::MaxCoOwners>,
+		/// The number of co-owners that must approve a proposal for it to take effect.
+		pub threshold: u32,
+	}
+
+	/// An action proposed by a co-owner of a claim, awaiting the approval of the others.
+	#[derive(
+		CloneNoBound,
+		Encode,
+		Decode,
+		EqNoBound,
+		PartialEqNoBound,
+		RuntimeDebugNoBound,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T, I))]
+	#[codec(mel_bound())]
+	pub struct Proposal<T: Config<I>, I: 'static = ()> {
+		/// The action to take once the proposal is approved.
+		pub action: ProposalAction<T::AccountId>,
+		/// The co-owners that approved the proposal so far, starting with its proposer.
+		pub approvals: BoundedVec<T::AccountId, T::MaxCoOwners>,
+		/// The last block at which the proposal can be approved.
+		pub expires_at: T::BlockNumber,
+	}
+
+	/// An attestation of a claim, with its note bounded by `MaxNoteLength`.
+	#[derive(
+		CloneNoBound,
+		Encode,
+		Decode,
+		EqNoBound,
+		PartialEqNoBound,
+		RuntimeDebugNoBound,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T, I))]
+	#[codec(mel_bound())]
+	pub struct AttestationInfo<T: Config<I>, I: 'static = ()> {
+		/// The account attesting to the claim.
+		pub attester: T::AccountId,
+		/// The block at which the attestation was made.
+		pub attested_at: T::BlockNumber,
+		/// A note left by the attester, if any.
+		pub note: Option<BoundedVec<u8, T::MaxNoteLength>>,
+		/// The amount reserved from the attester for the attestation.
+		pub deposit: BalanceOf<T, I>,
+	}
+
+	impl<T: Config<I>, I: 'static> From<AttestationInfo<T, I>>
+		for Attestation<T::AccountId, T::BlockNumber>
+	{
+		fn from(info: AttestationInfo<T, I>) -> Self {
+			Attestation {
+				attester: info.attester,
+				attested_at: info.attested_at,
+				note: info.note.map(BoundedVec::into_inner),
+			}
+		}
+	}
+
+	/// What is kept of a revoked claim, with its reason bounded by `MaxReasonLength`.
+	#[derive(
+		CloneNoBound,
+		Encode,
+		Decode,
+		EqNoBound,
+		PartialEqNoBound,
+		RuntimeDebugNoBound,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T, I))]
+	#[codec(mel_bound())]
+	pub struct Tombstone<T: Config<I>, I: 'static = ()> {
+		/// The account owning the claim when it was revoked.
+		pub owner: T::AccountId,
+		/// The account that revoked the claim, or `None` if it was revoked by `ForceOrigin`.
+		pub revoker: Option<T::AccountId>,
+		/// The block at which the claim was revoked.
+		pub revoked_at: T::BlockNumber,
+		/// The reason given for revoking the claim, if any.
+		pub reason: Option<BoundedVec<u8, T::MaxReasonLength>>,
+		/// The part of the claim deposit still reserved from the owner for the tombstone.
+		pub deposit: BalanceOf<T, I>,
+	}
+
+	impl<T: Config<I>, I: 'static> From<Tombstone<T, I>> for Revocation<T::AccountId, T::BlockNumber> {
+		fn from(tombstone: Tombstone<T, I>) -> Self {
+			Revocation {
+				owner: tombstone.owner,
+				revoker: tombstone.revoker,
+				revoked_at: tombstone.revoked_at,
+				reason: tombstone.reason.map(BoundedVec::into_inner),
+			}
+		}
+	}
+
+	// Define the pallet struct using the pallet::pallet macro
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T, I = ()>(_);
+
+	// Define the pallet's configuration trait
+	#[pallet::config]
+	pub trait Config<I: 'static = ()>: frame_system::Config {
+		type RuntimeEvent: From<Event<Self, I>>
+			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		#[pallet::constant]
+		type MaxClaimLength: Get<u32>;
+		/// The hasher used to turn raw claim bytes into the digest under which they are stored.
+		type ClaimHasher: HashT<Output = Self::Hash>;
+		/// The currency in which claim deposits are reserved.
+		type Currency: NamedReservableCurrency<Self::AccountId, ReserveIdentifier = [u8; 8]>;
+		/// The identifier under which claim deposits are reserved.
+		#[pallet::constant]
+		type ReserveId: Get<[u8; 8]>;
+		/// The base amount reserved for every claim.
+		#[pallet::constant]
+		type ClaimDepositBase: Get<BalanceOf<Self, I>>;
+		/// The amount reserved for every byte of claim data.
+		#[pallet::constant]
+		type ClaimDepositPerByte: Get<BalanceOf<Self, I>>;
+		/// The maximum length of the MIME type in claim metadata.
+		#[pallet::constant]
+		type MaxMimeTypeLength: Get<u32>;
+		/// The maximum length of the title in claim metadata.
+		#[pallet::constant]
+		type MaxTitleLength: Get<u32>;
+		/// The maximum length of the URI in claim metadata.
+		#[pallet::constant]
+		type MaxUriLength: Get<u32>;
+		/// The maximum number of attributes in claim metadata.
+		#[pallet::constant]
+		type MaxAttributes: Get<u32>;
+		/// The maximum length of an attribute key in claim metadata.
+		#[pallet::constant]
+		type MaxAttributeKeyLength: Get<u32>;
+		/// The maximum length of an attribute value in claim metadata.
+		#[pallet::constant]
+		type MaxAttributeValueLength: Get<u32>;
+		/// The base amount reserved for the metadata of a claim.
+		#[pallet::constant]
+		type MetadataDepositBase: Get<BalanceOf<Self, I>>;
+		/// The amount reserved for every byte of the MIME type in claim metadata.
+		#[pallet::constant]
+		type MimeTypeDepositPerByte: Get<BalanceOf<Self, I>>;
+		/// The amount reserved for every byte of the title in claim metadata.
+		#[pallet::constant]
+		type TitleDepositPerByte: Get<BalanceOf<Self, I>>;
+		/// The amount reserved for every byte of the URI in claim metadata.
+		#[pallet::constant]
+		type UriDepositPerByte: Get<BalanceOf<Self, I>>;
+		/// The amount reserved for every byte of the attribute keys and values in claim metadata.
+		#[pallet::constant]
+		type AttributeDepositPerByte: Get<BalanceOf<Self, I>>;
+		/// The share of the price of a sold claim paid to its creator, unless they are the seller.
+		#[pallet::constant]
+		type RoyaltyRate: Get<Permill>;
+		/// The maximum number of claims a single account may own.
+		#[pallet::constant]
+		type MaxClaimsPerAccount: Get<u32>;
+		/// The number of blocks a transfer offer stays open for.
+		#[pallet::constant]
+		type OfferDuration: Get<Self::BlockNumber>;
+		/// The number of blocks that must pass after a claim is committed to before it can be
+		/// revealed.
+		#[pallet::constant]
+		type MinRevealDelay: Get<Self::BlockNumber>;
+		/// The number of blocks after which a commitment can no longer be revealed, and can be
+		/// removed by anyone. Must be longer than `MinRevealDelay`.
+		#[pallet::constant]
+		type CommitmentLifetime: Get<Self::BlockNumber>;
+		/// The maximum number of custody records kept for a claim. Once reached, the oldest
+		/// records are dropped first.
+		#[pallet::constant]
+		type MaxHistoryLength: Get<u32>;
+		/// The maximum number of expired claims removed at the start of a block. Any left over are
+		/// removed once blocks have weight to spare.
+		#[pallet::constant]
+		type MaxExpiriesPerBlock: Get<u32>;
+		/// The origin allowed to freeze, revoke and transfer any claim.
+		type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// The origin allowed to create and manage claims, resolving to the account that owns
+		/// them and is checked whenever a claim is transferred or revoked. Origins other than
+		/// signed ones, such as root or a collective, can own claims through an account of their
+		/// own, for example with `EnsureRootWithSuccess`.
+		type ClaimOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
+		/// The maximum number of accounts owning a claim together.
+		#[pallet::constant]
+		type MaxCoOwners: Get<u32>;
+		/// The number of blocks a proposal on a co-owned claim stays open for.
+		#[pallet::constant]
+		type ProposalDuration: Get<Self::BlockNumber>;
+		/// The maximum number of attestations kept for a claim.
+		#[pallet::constant]
+		type MaxAttestations: Get<u32>;
+		/// The maximum length of the note left with an attestation.
+		#[pallet::constant]
+		type MaxNoteLength: Get<u32>;
+		/// The amount reserved from an account for every attestation it makes.
+		#[pallet::constant]
+		type AttestationDeposit: Get<BalanceOf<Self, I>>;
+		/// The maximum length of the reason given for revoking a claim.
+		#[pallet::constant]
+		type MaxReasonLength: Get<u32>;
+		/// Whether accounts other than its last owner may register a revoked claim again.
+		#[pallet::constant]
+		type AllowReregistration: Get<bool>;
+		/// The part of the claim deposit kept reserved from the owner of a revoked claim until its
+		/// tombstone is cleared.
+		#[pallet::constant]
+		type TombstoneDeposit: Get<BalanceOf<Self, I>>;
+		/// The source of the wall-clock time recorded when a claim is created or transferred.
+		type TimeProvider: UnixTime;
+		/// Weight information for the extrinsics of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	// Define the storage item for storing the claims, keyed by their digest, along with
+	// everything recorded about them
+	#[pallet::storage]
+	#[pallet::getter(fn something)]
+	pub type Proofs<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::Hash, ClaimInfoOf<T, I>>;
+
+	// The chain of owners of each claim, oldest first.
+	#[pallet::storage]
+	pub type ClaimHistory<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::Hash,
+		BoundedVec<CustodyRecord<T::AccountId, T::BlockNumber>, T::MaxHistoryLength>,
+		ValueQuery,
+	>;
+
+	// The number of leaves committed to by each claim that is the root of a merkle batch.
+	#[pallet::storage]
+	pub type BatchRoots<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::Hash, u32>;
+
+	// The claims expiring at each block.
+	#[pallet::storage]
+	pub type ExpiryQueue<T: Config<I>, I: 'static = ()> =
+		StorageDoubleMap<_, Twox64Concat, T::BlockNumber, Blake2_128Concat, T::Hash, ()>;
+
+	// The earliest block whose expiring claims may not all have been removed yet, or `None` if no
+	// claim was ever set to expire.
+	#[pallet::storage]
+	pub type ExpiryCursor<T: Config<I>, I: 'static = ()> = StorageValue<_, T::BlockNumber>;
 
-			// Get the owner of the claim and ensure that it exists.
-			let (owner, _) = Proofs::<T>::get(&bounded_claim).ok_or(Error::<T>::ClaimNotExists)?;
+	// The metadata attached to each claim, along with the deposit reserved for it.
+	#[pallet::storage]
+	pub type ClaimMetadataOf<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::Hash, MetadataInfo<T, I>>;
 
-			// Ensure that the sender is the owner of the claim.
-			ensure!(owner == sender, Error::<T>::NotClaimOwner);
+	// The co-owners of each claim owned by several accounts together.
+	#[pallet::storage]
+	pub type CoOwners<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::Hash, CoOwnership<T, I>>;
 
-			// Remove the claim.
-			Proofs::<T>::remove(&bounded_claim);
+	// The open proposal on each co-owned claim.
+	#[pallet::storage]
+	pub type Proposals<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::Hash, Proposal<T, I>>;
 
-			// Emit a ClaimRevoked event.
-			Self::deposit_event(Event::ClaimRevoked(sender, claim));
+	// The links between each claim and its previous and next revisions, for claims that are part of
+	// a revision chain.
+	#[pallet::storage]
+	pub type ClaimRevisions<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::Hash, ClaimRevision<T::Hash>>;
 
-			Ok(()) 
+	// What is kept of each revoked claim, until it is registered again.
+	#[pallet::storage]
+	pub type Tombstones<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::Hash, Tombstone<T, I>>;
+
+	// The attestations made of each claim, oldest first.
+	#[pallet::storage]
+	pub type Attestations<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::Hash,
+		BoundedVec<AttestationInfo<T, I>, T::MaxAttestations>,
+		ValueQuery,
+	>;
+
+	// The price of each claim up for sale.
+	#[pallet::storage]
+	pub type Listings<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::Hash, BalanceOf<T, I>>;
+
+	// Commitments to claims not yet revealed, keyed by the hash committed to.
+	#[pallet::storage]
+	pub type Commitments<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::Hash,
+		Commitment<T::AccountId, T::BlockNumber, BalanceOf<T, I>>,
+	>;
+
+	// Transfers offered by claim owners, awaiting acceptance.
+	#[pallet::storage]
+	pub type PendingTransfers<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::Hash, PendingTransfer<T::AccountId, T::BlockNumber>>;
+
+	// Reverse index of `Proofs`, listing the claims owned by each account.
+	#[pallet::storage]
+	pub type ClaimsByOwner<T: Config<I>, I: 'static = ()> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, T::Hash, ()>;
+
+	// The number of claims owned by each account.
+	#[pallet::storage]
+	#[pallet::getter(fn claim_count)]
+	pub type ClaimCount<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	// Claims registered at genesis, as raw claim bytes along with their owner.
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
+		pub claims: Vec<(Vec<u8>, T::AccountId)>,
+		pub phantom: PhantomData<I>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config<I>, I: 'static> Default for GenesisConfig<T, I> {
+		fn default() -> Self {
+			Self { claims: Default::default(), phantom: Default::default() }
 		}
-		
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config<I>, I: 'static> GenesisBuild<T, I> for GenesisConfig<T, I> {
+		fn build(&self) {
+			for (claim, owner) in &self.claims {
+				assert!(
+					claim.len() <= T::MaxClaimLength::get() as usize,
+					"genesis claim exceeds `MaxClaimLength`"
+				);
+				let digest = T::ClaimHasher::hash(claim);
+				assert!(!Proofs::<T, I>::contains_key(&digest), "duplicate genesis claim");
+				Pallet::<T, I>::do_create_claim(owner.clone(), digest, None, None)
+					.expect("genesis claim owners can hold the claim and cover its deposit");
+			}
+		}
+	}
+
+	// Define the events that can be emitted by the pallet
+	//
+	// Every event is deposited with the hash of each claim it concerns, and the `T::Hashing` hash
+	// of each account it concerns, as topics, so that it can be found through
+	// `System::EventTopics`.
+	#[pallet::event]
+	pub enum Event<T: Config<I>, I: 'static = ()> {
+		/// A claim was created, at the given time if it is known.
+		ClaimCreated { owner: T::AccountId, claim: T::Hash, created_time: Option<u64> },
+		/// A claim was revoked.
+		ClaimRevoked { owner: T::AccountId, claim: T::Hash },
+		/// A claim was transferred, at the given time if it is known.
+		ClaimTransferred {
+			from: T::AccountId,
+			to: T::AccountId,
+			claim: T::Hash,
+			transferred_time: Option<u64>,
+		},
+		/// A claim was offered to `dest` until `expires_at`.
+		ClaimOffered {
+			owner: T::AccountId,
+			dest: T::AccountId,
+			claim: T::Hash,
+			expires_at: T::BlockNumber,
+		},
+		/// The offer of a claim was withdrawn.
+		OfferCancelled { owner: T::AccountId, claim: T::Hash },
+		/// A claim was created as the root of a merkle batch of `leaf_count` documents.
+		BatchRootCreated { owner: T::AccountId, root: T::Hash, leaf_count: u32 },
+		/// A claim reached its expiry and was removed.
+		ClaimExpired { owner: T::AccountId, claim: T::Hash },
+		/// A claim was renewed until `expires_at`.
+		ClaimRenewed { owner: T::AccountId, claim: T::Hash, expires_at: T::BlockNumber },
+		/// A claim was frozen.
+		ClaimFrozen { owner: T::AccountId, claim: T::Hash },
+		/// The freeze on a claim was lifted.
+		ClaimUnfrozen { owner: T::AccountId, claim: T::Hash },
+		/// Metadata was attached to a claim.
+		MetadataSet { owner: T::AccountId, claim: T::Hash },
+		/// The metadata of a claim was removed.
+		MetadataCleared { owner: T::AccountId, claim: T::Hash },
+		/// An account committed to a claim without disclosing it.
+		ClaimCommitted { who: T::AccountId, commitment: T::Hash },
+		/// A commitment was removed without being revealed.
+		CommitmentRemoved { who: T::AccountId, commitment: T::Hash },
+		/// A claim was shared between co-owners, `threshold` of whom must approve proposals.
+		CoOwnersSet { owner: T::AccountId, claim: T::Hash, threshold: u32 },
+		/// A co-owner proposed to take `action` on a claim.
+		ProposalCreated {
+			proposer: T::AccountId,
+			claim: T::Hash,
+			action: ProposalAction<T::AccountId>,
+		},
+		/// A co-owner approved the open proposal on a claim.
+		ProposalApproved { approver: T::AccountId, claim: T::Hash },
+		/// The open proposal on a claim was approved and its action taken.
+		ProposalExecuted { claim: T::Hash },
+		/// The open proposal on a claim was withdrawn.
+		ProposalCancelled { claim: T::Hash },
+		/// An account attested to a claim.
+		ClaimAttested { attester: T::AccountId, claim: T::Hash },
+		/// An account withdrew its attestation of a claim.
+		AttestationWithdrawn { attester: T::AccountId, claim: T::Hash },
+		/// An attestation of a claim was removed by its owner or the force origin.
+		AttestationRemoved { attester: T::AccountId, claim: T::Hash },
+		/// The tombstone of a revoked claim was cleared, returning its deposit.
+		TombstoneCleared { owner: T::AccountId, claim: T::Hash },
+		/// A claim was superseded by a new revision.
+		ClaimSuperseded { owner: T::AccountId, old: T::Hash, new: T::Hash },
+		/// A claim was put up for sale.
+		ClaimListed { owner: T::AccountId, claim: T::Hash, price: BalanceOf<T, I> },
+		/// A claim was withdrawn from sale.
+		ClaimDelisted { owner: T::AccountId, claim: T::Hash },
+		/// A claim was sold, with `royalty` of its `price` paid to its creator.
+		ClaimSold {
+			seller: T::AccountId,
+			buyer: T::AccountId,
+			claim: T::Hash,
+			price: BalanceOf<T, I>,
+			royalty: BalanceOf<T, I>,
+		},
+	}
+
+	// Define the errors that can be returned by the pallet
+	#[pallet::error]
+	pub enum Error<T, I = ()> {
+		ProofAlreadyExists,
+		ClaimTooLong,
+		ClaimNotExists,
+		NotClaimOwner,
+		TooManyClaims,
+		NoPendingOffer,
+		NotOfferRecipient,
+		OfferExpired,
+		EmptyBatch,
+		ExpiryInPast,
+		ClaimNotExpiring,
+		ExpiryNotExtended,
+		ClaimFrozen,
+		ClaimNotFrozen,
+		MimeTypeTooLong,
+		TitleTooLong,
+		UriTooLong,
+		TooManyAttributes,
+		AttributeKeyTooLong,
+		AttributeValueTooLong,
+		NoMetadata,
+		CommitmentExists,
+		NoCommitment,
+		RevealTooEarly,
+		CommitmentExpired,
+		CommitmentNotExpired,
+		TooManyCoOwners,
+		InvalidCoOwners,
+		InvalidThreshold,
+		ClaimCoOwned,
+		ClaimNotCoOwned,
+		ProposalExists,
+		NoProposal,
+		ProposalExpired,
+		ProposalNotExpired,
+		AlreadyApproved,
+		NoteTooLong,
+		TooManyAttestations,
+		AlreadyAttested,
+		NotAttested,
+		AlreadySuperseded,
+		ReasonTooLong,
+		ClaimWasRevoked,
+		NoTombstone,
+		NotListed,
+		PriceTooHigh,
+		AlreadyClaimOwner,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			Self::prune_expired(now, T::WeightInfo::expire_claims(T::MaxExpiriesPerBlock::get()))
+		}
+
+		fn on_idle(now: T::BlockNumber, remaining_weight: Weight) -> Weight {
+			// Remove as many of the claims left over by `on_initialize` as the block has room for.
+			Self::prune_expired(now, remaining_weight)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: T::BlockNumber) -> Result<(), &'static str> {
+			Self::do_try_state()
+		}
+	}
+
+	// Define the pallet's dispatchable functions
+	#[pallet::call]
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		// Create a new claim from raw bytes, which are hashed with `T::ClaimHasher`.
+		// Only the digest is kept in storage. If `expires_at` is given, the claim is removed and
+		// its deposit returned once that block is reached. Any `metadata` is attached to the claim
+		// as with `set_claim_metadata`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(
+			T::WeightInfo::create_claim(claim.len() as u32).saturating_add(
+				metadata.as_ref().map_or(Weight::zero(), |_| T::WeightInfo::set_claim_metadata())
+			)
+		)]
+		pub fn create_claim(
+			origin: OriginFor<T>,
+			claim: Vec<u8>,
+			expires_at: Option<T::BlockNumber>,
+			metadata: Option<ClaimMetadata>,
+		) -> DispatchResult {
+			// Resolve the account the origin acts for
+			let who = T::ClaimOrigin::ensure_origin(origin)?;
+			// Ensure that the claim is not too long
+			ensure!(claim.len() <= T::MaxClaimLength::get() as usize, Error::<T, I>::ClaimTooLong);
+			// Anchor the digest of the claim data
+			let claim = T::ClaimHasher::hash(&claim);
+			Self::do_create_claim(who.clone(), claim, None, expires_at)?;
+			if let Some(metadata) = metadata {
+				Self::do_set_metadata(&who, &claim, metadata)?;
+			}
+			Ok(())
+		}
+
+		// Revoke an existing claim, optionally giving a `reason`. A tombstone is kept in its place,
+		// holding `TombstoneDeposit` of the claim deposit until it is cleared.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::revoke_claim(T::MaxAttestations::get()))]
+		pub fn revoke_claim(
+			origin: OriginFor<T>,
+			claim: T::Hash,
+			reason: Option<Vec<u8>>,
+		) -> DispatchResult {
+			// Resolve the account the origin acts for
+			let sender = T::ClaimOrigin::ensure_origin(origin)?;
+			let reason = Self::bound_reason(reason)?;
+
+			Self::revoke_as_owner(sender, claim, reason)
+		}
+
 		/// transfer the claim from a account id to another account id.
 		#[pallet::call_index(2)]
-		#[pallet::weight(0)]
-		pub fn transfer_claim(origin: OriginFor<T>, claim: Vec<u8>, dest: T::AccountId) -> DispatchResult{
-			// Ensure that the transaction is signed by the sender.
-			let sender = ensure_signed(origin)?;
-
-			// Ensure that the claim is not too long.
-			let bounded_claim = BoundedVec::<u8, T::MaxClaimLength>::try_from(claim.clone()).map_err(
-				|_| Error::<T>::ClaimTooLong
+		#[pallet::weight(T::WeightInfo::transfer_claim())]
+		pub fn transfer_claim(
+			origin: OriginFor<T>,
+			claim: T::Hash,
+			dest: T::AccountId,
+		) -> DispatchResult {
+			// Resolve the account the origin acts for.
+			let sender = T::ClaimOrigin::ensure_origin(origin)?;
+
+			Self::transfer_as_owner(&sender, claim, dest)
+		}
+
+		/// Create a new claim from a digest computed off-chain, so documents of any size can be
+		/// anchored. `algorithm` optionally records how the digest was produced.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::create_hashed_claim())]
+		pub fn create_hashed_claim(
+			origin: OriginFor<T>,
+			digest: T::Hash,
+			algorithm: Option<HashAlgorithm>,
+		) -> DispatchResult {
+			let who = T::ClaimOrigin::ensure_origin(origin)?;
+			Self::do_create_claim(who, digest, algorithm, None)
+		}
+
+		/// Offer a claim to `dest`, who becomes its owner once they accept the offer. The offer
+		/// replaces any previous one and expires after `OfferDuration` blocks.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::offer_claim())]
+		pub fn offer_claim(
+			origin: OriginFor<T>,
+			claim: T::Hash,
+			dest: T::AccountId,
+		) -> DispatchResult {
+			let sender = T::ClaimOrigin::ensure_origin(origin)?;
+
+			// Get the owner of the claim and ensure that it is the sender alone.
+			let info = Proofs::<T, I>::get(&claim).ok_or(Error::<T, I>::ClaimNotExists)?;
+			Self::ensure_sole_owner(&claim, &info, &sender)?;
+			ensure!(!info.frozen, Error::<T, I>::ClaimFrozen);
+
+			// Record the offer until it expires.
+			let expires_at =
+				frame_system::Pallet::<T>::block_number().saturating_add(T::OfferDuration::get());
+			PendingTransfers::<T, I>::insert(
+				&claim,
+				PendingTransfer { dest: dest.clone(), expires_at },
+			);
+
+			Self::deposit_indexed(
+				Event::ClaimOffered {
+					owner: sender.clone(),
+					dest: dest.clone(),
+					claim,
+					expires_at,
+				},
+				&[claim],
+				&[&sender, &dest],
+			);
+			Ok(())
+		}
+
+		/// Accept a claim offered to the sender, taking over its ownership and deposit.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::accept_claim())]
+		pub fn accept_claim(origin: OriginFor<T>, claim: T::Hash) -> DispatchResult {
+			let sender = T::ClaimOrigin::ensure_origin(origin)?;
+
+			// Ensure the offer is addressed to the sender and still open.
+			let offer =
+				PendingTransfers::<T, I>::get(&claim).ok_or(Error::<T, I>::NoPendingOffer)?;
+			ensure!(offer.dest == sender, Error::<T, I>::NotOfferRecipient);
+			ensure!(
+				frame_system::Pallet::<T>::block_number() <= offer.expires_at,
+				Error::<T, I>::OfferExpired
+			);
+
+			let info = Proofs::<T, I>::get(&claim).ok_or(Error::<T, I>::ClaimNotExists)?;
+			ensure!(!info.frozen, Error::<T, I>::ClaimFrozen);
+			Self::do_transfer_claim(claim, info, sender)
+		}
+
+		/// Withdraw the pending offer of a claim.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::cancel_offer())]
+		pub fn cancel_offer(origin: OriginFor<T>, claim: T::Hash) -> DispatchResult {
+			let sender = T::ClaimOrigin::ensure_origin(origin)?;
+
+			let info = Proofs::<T, I>::get(&claim).ok_or(Error::<T, I>::ClaimNotExists)?;
+			ensure!(info.owner == sender, Error::<T, I>::NotClaimOwner);
+			ensure!(PendingTransfers::<T, I>::contains_key(&claim), Error::<T, I>::NoPendingOffer);
+
+			PendingTransfers::<T, I>::remove(&claim);
+
+			Self::deposit_indexed(
+				Event::OfferCancelled { owner: sender.clone(), claim },
+				&[claim],
+				&[&sender],
+			);
+			Ok(())
+		}
+
+		/// Anchor a batch of `leaf_count` documents at once by claiming the root of a binary
+		/// merkle tree over their digests.
+		///
+		/// Each leaf is the `T::ClaimHasher` hash of a `0x00` byte followed by the document digest,
+		/// and each parent the hash of a `0x01` byte followed by its two children in ascending
+		/// order, so that a node cannot pass for a leaf. An unpaired node is carried up to the next
+		/// level unchanged. The root is an ordinary claim, and [`Pallet::verify_inclusion`] proves
+		/// each document against it.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::create_batch_root())]
+		pub fn create_batch_root(
+			origin: OriginFor<T>,
+			root: T::Hash,
+			leaf_count: u32,
+		) -> DispatchResult {
+			let who = T::ClaimOrigin::ensure_origin(origin)?;
+			ensure!(leaf_count > 0, Error::<T, I>::EmptyBatch);
+
+			Self::do_create_claim(who.clone(), root, None, None)?;
+			BatchRoots::<T, I>::insert(&root, leaf_count);
+
+			Self::deposit_indexed(
+				Event::BatchRootCreated { owner: who.clone(), root, leaf_count },
+				&[root],
+				&[&who],
+			);
+			Ok(())
+		}
+
+		/// Push the expiry of an expiring claim that is not frozen out to `expires_at`.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::renew_claim())]
+		pub fn renew_claim(
+			origin: OriginFor<T>,
+			claim: T::Hash,
+			expires_at: T::BlockNumber,
+		) -> DispatchResult {
+			let sender = T::ClaimOrigin::ensure_origin(origin)?;
+
+			let mut info = Proofs::<T, I>::get(&claim).ok_or(Error::<T, I>::ClaimNotExists)?;
+			Self::ensure_owner(&claim, &info, &sender)?;
+			ensure!(!info.frozen, Error::<T, I>::ClaimFrozen);
+			let current = info.expires_at.ok_or(Error::<T, I>::ClaimNotExpiring)?;
+			ensure!(expires_at > current, Error::<T, I>::ExpiryNotExtended);
+
+			// Move the claim to its new place in the queue.
+			ExpiryQueue::<T, I>::remove(current, &claim);
+			Self::schedule_expiry(&claim, expires_at)?;
+			info.expires_at = Some(expires_at);
+			Proofs::<T, I>::insert(&claim, info);
+
+			Self::deposit_indexed(
+				Event::ClaimRenewed { owner: sender.clone(), claim, expires_at },
+				&[claim],
+				&[&sender],
+			);
+			Ok(())
+		}
+
+		/// Revoke any claim, frozen or not, returning the deposit to its owner and optionally
+		/// giving a `reason`.
+		///
+		/// The dispatch origin must be `T::ForceOrigin`.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::force_revoke(T::MaxAttestations::get()))]
+		pub fn force_revoke(
+			origin: OriginFor<T>,
+			claim: T::Hash,
+			reason: Option<Vec<u8>>,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let reason = Self::bound_reason(reason)?;
+
+			let info = Proofs::<T, I>::get(&claim).ok_or(Error::<T, I>::ClaimNotExists)?;
+			Self::do_revoke_claim(claim, info, None, reason)
+		}
+
+		/// Transfer any claim, frozen or not, to `dest`, along with its deposit.
+		///
+		/// The dispatch origin must be `T::ForceOrigin`.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::force_transfer())]
+		pub fn force_transfer(
+			origin: OriginFor<T>,
+			claim: T::Hash,
+			dest: T::AccountId,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			let info = Proofs::<T, I>::get(&claim).ok_or(Error::<T, I>::ClaimNotExists)?;
+			Self::do_transfer_claim(claim, info, dest)
+		}
+
+		/// Freeze a claim, so that its owner can no longer transfer or revoke it.
+		///
+		/// The dispatch origin must be `T::ForceOrigin`.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::freeze_claim())]
+		pub fn freeze_claim(origin: OriginFor<T>, claim: T::Hash) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			let owner = Proofs::<T, I>::try_mutate(&claim, |info| -> Result<_, DispatchError> {
+				let info = info.as_mut().ok_or(Error::<T, I>::ClaimNotExists)?;
+				ensure!(!info.frozen, Error::<T, I>::ClaimFrozen);
+				info.frozen = true;
+				Ok(info.owner.clone())
+			})?;
+
+			Self::deposit_indexed(
+				Event::ClaimFrozen { owner: owner.clone(), claim },
+				&[claim],
+				&[&owner],
+			);
+			Ok(())
+		}
+
+		/// Lift the freeze on a claim.
+		///
+		/// The dispatch origin must be `T::ForceOrigin`.
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::unfreeze_claim())]
+		pub fn unfreeze_claim(origin: OriginFor<T>, claim: T::Hash) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			let owner = Proofs::<T, I>::try_mutate(&claim, |info| -> Result<_, DispatchError> {
+				let info = info.as_mut().ok_or(Error::<T, I>::ClaimNotExists)?;
+				ensure!(info.frozen, Error::<T, I>::ClaimNotFrozen);
+				info.frozen = false;
+				Ok(info.owner.clone())
+			})?;
+
+			Self::deposit_indexed(
+				Event::ClaimUnfrozen { owner: owner.clone(), claim },
+				&[claim],
+				&[&owner],
+			);
+			Ok(())
+		}
+
+		/// Attach `metadata` to a claim, replacing any it already has.
+		///
+		/// The deposit reserved for the metadata is adjusted to its new size. The metadata of a
+		/// co-owned claim cannot be changed, as that would move the deposit of its owner.
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::set_claim_metadata())]
+		pub fn set_claim_metadata(
+			origin: OriginFor<T>,
+			claim: T::Hash,
+			metadata: ClaimMetadata,
+		) -> DispatchResult {
+			let sender = T::ClaimOrigin::ensure_origin(origin)?;
+
+			let info = Proofs::<T, I>::get(&claim).ok_or(Error::<T, I>::ClaimNotExists)?;
+			Self::ensure_sole_owner(&claim, &info, &sender)?;
+			ensure!(!info.frozen, Error::<T, I>::ClaimFrozen);
+
+			Self::do_set_metadata(&info.owner, &claim, metadata)
+		}
+
+		/// Remove the metadata of a claim, returning the deposit reserved for it.
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::clear_claim_metadata())]
+		pub fn clear_claim_metadata(origin: OriginFor<T>, claim: T::Hash) -> DispatchResult {
+			let sender = T::ClaimOrigin::ensure_origin(origin)?;
+
+			let info = Proofs::<T, I>::get(&claim).ok_or(Error::<T, I>::ClaimNotExists)?;
+			Self::ensure_sole_owner(&claim, &info, &sender)?;
+			ensure!(!info.frozen, Error::<T, I>::ClaimFrozen);
+
+			let metadata =
+				ClaimMetadataOf::<T, I>::take(&claim).ok_or(Error::<T, I>::NoMetadata)?;
+			T::Currency::unreserve_named(&T::ReserveId::get(), &info.owner, metadata.deposit);
+
+			Self::deposit_indexed(
+				Event::MetadataCleared { owner: info.owner.clone(), claim },
+				&[claim],
+				&[&info.owner],
+			);
+			Ok(())
+		}
+
+		/// Commit to a claim without disclosing it, reserving the claim deposit.
+		///
+		/// `commitment` is the `T::ClaimHasher` hash of the SCALE-encoded tuple of the claim
+		/// digest, a secret 32-byte salt and the committing account, as computed by
+		/// [`Pallet::commitment_of`]. The claim is registered by revealing it with
+		/// `reveal_claim` once `MinRevealDelay` blocks have passed.
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::commit_claim())]
+		pub fn commit_claim(origin: OriginFor<T>, commitment: T::Hash) -> DispatchResult {
+			let who = T::ClaimOrigin::ensure_origin(origin)?;
+			ensure!(
+				!Commitments::<T, I>::contains_key(&commitment),
+				Error::<T, I>::CommitmentExists
+			);
+
+			let deposit = Self::claim_deposit();
+			T::Currency::reserve_named(&T::ReserveId::get(), &who, deposit)?;
+			let committed_at = frame_system::Pallet::<T>::block_number();
+			Commitments::<T, I>::insert(
+				&commitment,
+				Commitment { who: who.clone(), committed_at, deposit },
+			);
+
+			Self::deposit_indexed(
+				Event::ClaimCommitted { who: who.clone(), commitment },
+				&[commitment],
+				&[&who],
+			);
+			Ok(())
+		}
+
+		/// Register a claim committed to with `commit_claim`, along with the salt it was
+		/// committed with.
+		///
+		/// The earliest commitment wins: a claim registered by anyone else after the commitment
+		/// was made is revoked, returning its deposit, and the claim goes to the committer
+		/// instead.
+		#[pallet::call_index(16)]
+		#[pallet::weight(
+			T::WeightInfo::reveal_claim(claim.len() as u32, T::MaxAttestations::get())
+		)]
+		pub fn reveal_claim(
+			origin: OriginFor<T>,
+			claim: Vec<u8>,
+			salt: [u8; 32],
+		) -> DispatchResult {
+			let who = T::ClaimOrigin::ensure_origin(origin)?;
+			ensure!(claim.len() <= T::MaxClaimLength::get() as usize, Error::<T, I>::ClaimTooLong);
+
+			let claim = T::ClaimHasher::hash(&claim);
+			let commitment = Self::commitment_of(&claim, &salt, &who);
+			let record =
+				Commitments::<T, I>::get(&commitment).ok_or(Error::<T, I>::NoCommitment)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(
+				now >= record.committed_at.saturating_add(T::MinRevealDelay::get()),
+				Error::<T, I>::RevealTooEarly
+			);
+			ensure!(
+				now <= record.committed_at.saturating_add(T::CommitmentLifetime::get()),
+				Error::<T, I>::CommitmentExpired
+			);
+
+			// A claim registered after the commitment was made gives way to it, unless frozen.
+			if let Some(info) = Proofs::<T, I>::get(&claim) {
+				ensure!(
+					!info.frozen && info.created_at > record.committed_at,
+					Error::<T, I>::ProofAlreadyExists
+				);
+				Self::remove_claim(&claim, &info);
+				Self::deposit_indexed(
+					Event::ClaimRevoked { owner: info.owner.clone(), claim },
+					&[claim],
+					&[&info.owner],
+				);
+			}
+
+			Commitments::<T, I>::remove(&commitment);
+			T::Currency::unreserve_named(&T::ReserveId::get(), &who, record.deposit);
+			Self::do_create_claim(who, claim, None, None)
+		}
+
+		/// Remove a commitment, returning its deposit to the account that made it.
+		///
+		/// The account that made the commitment can remove it at any time, anyone else only once
+		/// `CommitmentLifetime` has passed.
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::remove_commitment())]
+		pub fn remove_commitment(origin: OriginFor<T>, commitment: T::Hash) -> DispatchResult {
+			let sender = T::ClaimOrigin::ensure_origin(origin)?;
+
+			let record =
+				Commitments::<T, I>::get(&commitment).ok_or(Error::<T, I>::NoCommitment)?;
+			if record.who != sender {
+				let now = frame_system::Pallet::<T>::block_number();
+				ensure!(
+					now > record.committed_at.saturating_add(T::CommitmentLifetime::get()),
+					Error::<T, I>::CommitmentNotExpired
+				);
+			}
+
+			Commitments::<T, I>::remove(&commitment);
+			T::Currency::unreserve_named(&T::ReserveId::get(), &record.who, record.deposit);
+
+			Self::deposit_indexed(
+				Event::CommitmentRemoved { who: record.who.clone(), commitment },
+				&[commitment],
+				&[&record.who],
+			);
+			Ok(())
+		}
+
+		/// Share a claim between `owners`, who must include the sender, so that it can only be
+		/// revoked or transferred once `threshold` of them approve.
+		///
+		/// The sender keeps holding the deposits of the claim. Any pending offer or listing is
+		/// withdrawn.
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::set_co_owners(owners.len() as u32))]
+		pub fn set_co_owners(
+			origin: OriginFor<T>,
+			claim: T::Hash,
+			owners: Vec<T::AccountId>,
+			threshold: u32,
+		) -> DispatchResult {
+			let sender = T::ClaimOrigin::ensure_origin(origin)?;
+
+			let info = Proofs::<T, I>::get(&claim).ok_or(Error::<T, I>::ClaimNotExists)?;
+			Self::ensure_sole_owner(&claim, &info, &sender)?;
+			ensure!(!info.frozen, Error::<T, I>::ClaimFrozen);
+
+			let owners: BoundedVec<_, T::MaxCoOwners> =
+				owners.try_into().map_err(|_| Error::<T, I>::TooManyCoOwners)?;
+			let mut sorted = owners.clone().into_inner();
+			sorted.sort();
+			sorted.dedup();
+			ensure!(
+				sorted.len() == owners.len() && owners.contains(&sender),
+				Error::<T, I>::InvalidCoOwners
+			);
+			ensure!(
+				threshold > 0 && threshold as usize <= owners.len(),
+				Error::<T, I>::InvalidThreshold
+			);
+
+			CoOwners::<T, I>::insert(&claim, CoOwnership { owners: owners.clone(), threshold });
+			PendingTransfers::<T, I>::remove(&claim);
+			Listings::<T, I>::remove(&claim);
+
+			// Index the event under every co-owner.
+			Self::deposit_indexed(
+				Event::CoOwnersSet { owner: sender, claim, threshold },
+				&[claim],
+				&owners.iter().collect::<Vec<_>>(),
+			);
+			Ok(())
+		}
+
+		/// Propose to revoke a co-owned claim, approving the proposal as its proposer.
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::propose_revoke(T::MaxAttestations::get()))]
+		pub fn propose_revoke(origin: OriginFor<T>, claim: T::Hash) -> DispatchResult {
+			let sender = T::ClaimOrigin::ensure_origin(origin)?;
+			Self::do_propose(sender, claim, ProposalAction::Revoke)
+		}
+
+		/// Propose to transfer a co-owned claim to `dest`, approving the proposal as its
+		/// proposer.
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::propose_transfer())]
+		pub fn propose_transfer(
+			origin: OriginFor<T>,
+			claim: T::Hash,
+			dest: T::AccountId,
+		) -> DispatchResult {
+			let sender = T::ClaimOrigin::ensure_origin(origin)?;
+			Self::do_propose(sender, claim, ProposalAction::Transfer(dest))
+		}
+
+		/// Approve the open proposal on a co-owned claim, taking its action once enough
+		/// co-owners have approved it. Either action may be taken, so the costlier is charged.
+		#[pallet::call_index(21)]
+		#[pallet::weight(
+			T::WeightInfo::approve_transfer_proposal()
+				.max(T::WeightInfo::approve_revoke_proposal(T::MaxAttestations::get()))
+		)]
+		pub fn approve_proposal(origin: OriginFor<T>, claim: T::Hash) -> DispatchResult {
+			let sender = T::ClaimOrigin::ensure_origin(origin)?;
+
+			let info = Proofs::<T, I>::get(&claim).ok_or(Error::<T, I>::ClaimNotExists)?;
+			Self::ensure_owner(&claim, &info, &sender)?;
+			let mut proposal = Proposals::<T, I>::get(&claim).ok_or(Error::<T, I>::NoProposal)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() <= proposal.expires_at,
+				Error::<T, I>::ProposalExpired
+			);
+			ensure!(!proposal.approvals.contains(&sender), Error::<T, I>::AlreadyApproved);
+
+			// Approvals come from distinct co-owners, so they always fit.
+			proposal
+				.approvals
+				.try_push(sender.clone())
+				.map_err(|_| Error::<T, I>::TooManyCoOwners)?;
+			Self::deposit_indexed(
+				Event::ProposalApproved { approver: sender.clone(), claim },
+				&[claim],
+				&[&sender],
+			);
+			Self::try_execute(claim, info, proposal)
+		}
+
+		/// Withdraw the open proposal on a co-owned claim.
+		///
+		/// Its proposer can withdraw it at any time, anyone else only once it has expired.
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::cancel_proposal())]
+		pub fn cancel_proposal(origin: OriginFor<T>, claim: T::Hash) -> DispatchResult {
+			let sender = T::ClaimOrigin::ensure_origin(origin)?;
+
+			let proposal = Proposals::<T, I>::get(&claim).ok_or(Error::<T, I>::NoProposal)?;
+			if proposal.approvals.first() != Some(&sender) {
+				ensure!(
+					frame_system::Pallet::<T>::block_number() > proposal.expires_at,
+					Error::<T, I>::ProposalNotExpired
+				);
+			}
+
+			Proposals::<T, I>::remove(&claim);
+			Self::deposit_indexed(Event::ProposalCancelled { claim }, &[claim], &[&sender]);
+			Ok(())
+		}
+
+		/// Attest to having seen the document behind a claim, optionally leaving a `note`.
+		///
+		/// Attesting gives no rights over the claim. `AttestationDeposit` is reserved from the
+		/// sender until the attestation is withdrawn, removed or the claim is removed.
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::attest_claim())]
+		pub fn attest_claim(
+			origin: OriginFor<T>,
+			claim: T::Hash,
+			note: Option<Vec<u8>>,
+		) -> DispatchResult {
+			let sender = T::ClaimOrigin::ensure_origin(origin)?;
+			ensure!(Proofs::<T, I>::contains_key(&claim), Error::<T, I>::ClaimNotExists);
+
+			let note = note
+				.map(|note| note.try_into().map_err(|_| Error::<T, I>::NoteTooLong))
+				.transpose()?;
+			Attestations::<T, I>::try_mutate(&claim, |attestations| -> DispatchResult {
+				ensure!(
+					!attestations.iter().any(|attestation| attestation.attester == sender),
+					Error::<T, I>::AlreadyAttested
+				);
+				let attested_at = frame_system::Pallet::<T>::block_number();
+				let deposit = T::AttestationDeposit::get();
+				attestations
+					.try_push(AttestationInfo {
+						attester: sender.clone(),
+						attested_at,
+						note,
+						deposit,
+					})
+					.map_err(|_| Error::<T, I>::TooManyAttestations)?;
+				T::Currency::reserve_named(&T::ReserveId::get(), &sender, deposit)
+			})?;
+
+			Self::deposit_indexed(
+				Event::ClaimAttested { attester: sender.clone(), claim },
+				&[claim],
+				&[&sender],
+			);
+			Ok(())
+		}
+
+		/// Withdraw the attestation the sender made of a claim, returning its deposit.
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::WeightInfo::withdraw_attestation())]
+		pub fn withdraw_attestation(origin: OriginFor<T>, claim: T::Hash) -> DispatchResult {
+			let sender = T::ClaimOrigin::ensure_origin(origin)?;
+
+			Self::remove_attestation_of(&claim, &sender)?;
+
+			Self::deposit_indexed(
+				Event::AttestationWithdrawn { attester: sender.clone(), claim },
+				&[claim],
+				&[&sender],
+			);
+			Ok(())
+		}
+
+		/// Register `new` as the next revision of the claim `old`, which is kept but marked as
+		/// superseded.
+		///
+		/// The new claim is owned by the sender and recorded with the algorithm of the old one.
+		/// A claim can only be superseded once.
+		#[pallet::call_index(25)]
+		#[pallet::weight(T::WeightInfo::supersede_claim())]
+		pub fn supersede_claim(origin: OriginFor<T>, old: T::Hash, new: T::Hash) -> DispatchResult {
+			let sender = T::ClaimOrigin::ensure_origin(origin)?;
+
+			let info = Proofs::<T, I>::get(&old).ok_or(Error::<T, I>::ClaimNotExists)?;
+			Self::ensure_sole_owner(&old, &info, &sender)?;
+			ensure!(!info.frozen, Error::<T, I>::ClaimFrozen);
+			ensure!(
+				ClaimRevisions::<T, I>::get(&old).and_then(|revision| revision.next).is_none(),
+				Error::<T, I>::AlreadySuperseded
+			);
+
+			Self::do_create_claim(sender.clone(), new, info.algorithm, None)?;
+			Self::update_revision(&old, |revision| revision.next = Some(new));
+			Self::update_revision(&new, |revision| revision.previous = Some(old));
+
+			Self::deposit_indexed(
+				Event::ClaimSuperseded { owner: sender.clone(), old, new },
+				&[old, new],
+				&[&sender],
+			);
+			Ok(())
+		}
+
+		/// Put a claim up for sale at `price`, replacing any earlier listing. It can then be
+		/// bought by anyone with `buy_claim`, until it changes hands or is delisted.
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::list_claim())]
+		pub fn list_claim(
+			origin: OriginFor<T>,
+			claim: T::Hash,
+			price: BalanceOf<T, I>,
+		) -> DispatchResult {
+			let sender = T::ClaimOrigin::ensure_origin(origin)?;
+
+			let info = Proofs::<T, I>::get(&claim).ok_or(Error::<T, I>::ClaimNotExists)?;
+			Self::ensure_sole_owner(&claim, &info, &sender)?;
+			ensure!(!info.frozen, Error::<T, I>::ClaimFrozen);
+
+			Listings::<T, I>::insert(&claim, price);
+
+			Self::deposit_indexed(
+				Event::ClaimListed { owner: sender.clone(), claim, price },
+				&[claim],
+				&[&sender],
+			);
+			Ok(())
+		}
+
+		/// Withdraw a claim from sale.
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::delist_claim())]
+		pub fn delist_claim(origin: OriginFor<T>, claim: T::Hash) -> DispatchResult {
+			let sender = T::ClaimOrigin::ensure_origin(origin)?;
+
+			let info = Proofs::<T, I>::get(&claim).ok_or(Error::<T, I>::ClaimNotExists)?;
+			ensure!(info.owner == sender, Error::<T, I>::NotClaimOwner);
+			ensure!(Listings::<T, I>::contains_key(&claim), Error::<T, I>::NotListed);
+
+			Listings::<T, I>::remove(&claim);
+
+			Self::deposit_indexed(
+				Event::ClaimDelisted { owner: sender.clone(), claim },
+				&[claim],
+				&[&sender],
+			);
+			Ok(())
+		}
+
+		/// Buy a claim up for sale, paying its price as long as it does not exceed `max_price`.
+		///
+		/// The price goes to the owner, less a `RoyaltyRate` share paid to the creator of the
+		/// claim if they are someone else. A royalty too small to recreate the account of a
+		/// creator that was reaped goes to the owner as well. The buyer takes the claim over along
+		/// with its deposits.
+		#[pallet::call_index(28)]
+		#[pallet::weight(T::WeightInfo::buy_claim())]
+		pub fn buy_claim(
+			origin: OriginFor<T>,
+			claim: T::Hash,
+			max_price: BalanceOf<T, I>,
+		) -> DispatchResult {
+			let buyer = T::ClaimOrigin::ensure_origin(origin)?;
+
+			let price = Listings::<T, I>::get(&claim).ok_or(Error::<T, I>::NotListed)?;
+			ensure!(price <= max_price, Error::<T, I>::PriceTooHigh);
+			let info = Proofs::<T, I>::get(&claim).ok_or(Error::<T, I>::ClaimNotExists)?;
+			ensure!(info.owner != buyer, Error::<T, I>::AlreadyClaimOwner);
+			ensure!(!info.frozen, Error::<T, I>::ClaimFrozen);
+
+			// Pay the creator their royalty and the seller the rest of the price.
+			let seller = info.owner.clone();
+			let creator = info.creator.clone();
+			let royalty =
+				if creator == seller { Zero::zero() } else { T::RoyaltyRate::get() * price };
+			let royalty = if T::Currency::total_balance(&creator).is_zero() &&
+				royalty < T::Currency::minimum_balance()
+			{
+				Zero::zero()
+			} else {
+				royalty
+			};
+			if !royalty.is_zero() {
+				T::Currency::transfer(&buyer, &creator, royalty, ExistenceRequirement::KeepAlive)?;
+			}
+			T::Currency::transfer(
+				&buyer,
+				&seller,
+				price.saturating_sub(royalty),
+				ExistenceRequirement::KeepAlive,
 			)?;
 
-			// Get the owner of the claim and ensure that it exists.
-			let (owner, _) = Proofs::<T>::get(&bounded_claim).ok_or(Error::<T>::ClaimNotExists)?;
+			// Hand the claim over, which also ends its listing.
+			Self::do_transfer_claim(claim, info, buyer.clone())?;
+
+			Self::deposit_indexed(
+				Event::ClaimSold {
+					seller: seller.clone(),
+					buyer: buyer.clone(),
+					claim,
+					price,
+					royalty,
+				},
+				&[claim],
+				&[&seller, &buyer],
+			);
+			Ok(())
+		}
+
+		/// Remove the attestation `attester` made of a claim, returning its deposit to them.
+		///
+		/// The origin must be the force origin or one of the owners of the claim.
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::WeightInfo::remove_attestation())]
+		pub fn remove_attestation(
+			origin: OriginFor<T>,
+			claim: T::Hash,
+			attester: T::AccountId,
+		) -> DispatchResult {
+			if let Err(origin) = T::ForceOrigin::try_origin(origin) {
+				let sender = T::ClaimOrigin::ensure_origin(origin)?;
+				let info = Proofs::<T, I>::get(&claim).ok_or(Error::<T, I>::ClaimNotExists)?;
+				Self::ensure_owner(&claim, &info, &sender)?;
+			}
+
+			Self::remove_attestation_of(&claim, &attester)?;
+
+			Self::deposit_indexed(
+				Event::AttestationRemoved { attester: attester.clone(), claim },
+				&[claim],
+				&[&attester],
+			);
+			Ok(())
+		}
+
+		/// Clear the tombstone of a revoked claim, returning its deposit to the last owner and
+		/// letting anyone register the claim again.
+		///
+		/// The origin must be the force origin, or the last owner of a claim they revoked
+		/// themselves.
+		#[pallet::call_index(30)]
+		#[pallet::weight(T::WeightInfo::clear_tombstone())]
+		pub fn clear_tombstone(origin: OriginFor<T>, claim: T::Hash) -> DispatchResult {
+			let forced = T::ForceOrigin::try_origin(origin);
+			let tombstone = Tombstones::<T, I>::get(&claim).ok_or(Error::<T, I>::NoTombstone)?;
+			if let Err(origin) = forced {
+				let sender = T::ClaimOrigin::ensure_origin(origin)?;
+				ensure!(tombstone.owner == sender, Error::<T, I>::NotClaimOwner);
+				ensure!(tombstone.revoker.is_some(), Error::<T, I>::ClaimWasRevoked);
+			}
+
+			Tombstones::<T, I>::remove(&claim);
+			T::Currency::unreserve_named(&T::ReserveId::get(), &tombstone.owner, tombstone.deposit);
 
-			// Ensure that the sender is the owner of the claim.
-			ensure!(owner == sender, Error::<T>::NotClaimOwner);
+			Self::deposit_indexed(
+				Event::TombstoneCleared { owner: tombstone.owner.clone(), claim },
+				&[claim],
+				&[&tombstone.owner],
+			);
+			Ok(())
+		}
+	}
+
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		fn do_create_claim(
+			who: T::AccountId,
+			claim: T::Hash,
+			algorithm: Option<HashAlgorithm>,
+			expires_at: Option<T::BlockNumber>,
+		) -> DispatchResult {
+			// Ensure that the claim does not already exist
+			ensure!(!Proofs::<T, I>::contains_key(&claim), Error::<T, I>::ProofAlreadyExists);
+			// A claim revoked by its owner may be reserved for them, one revoked by `ForceOrigin`
+			// is not
+			if let Some(tombstone) = Tombstones::<T, I>::take(&claim) {
+				ensure!(
+					T::AllowReregistration::get() ||
+						(tombstone.revoker.is_some() && tombstone.owner == who),
+					Error::<T, I>::ClaimWasRevoked
+				);
+				T::Currency::unreserve_named(
+					&T::ReserveId::get(),
+					&tombstone.owner,
+					tombstone.deposit,
+				);
+			}
+			// Index the claim under its owner
+			Self::index_claim(&who, &claim)?;
+			// Reserve the deposit for the storage taken by the claim
+			let deposit = Self::claim_deposit();
+			T::Currency::reserve_named(&T::ReserveId::get(), &who, deposit)?;
+			if let Some(expires_at) = expires_at {
+				Self::schedule_expiry(&claim, expires_at)?;
+			}
+			// Store the claim along with the owner's account ID and the current block number
+			let now = frame_system::Pallet::<T>::block_number();
+			let time = Self::now_time();
+			let info = ClaimInfo {
+				owner: who.clone(),
+				creator: who.clone(),
+				created_at: now,
+				created_time: time,
+				transferred_at: None,
+				transferred_time: None,
+				algorithm,
+				expires_at,
+				frozen: false,
+				deposit,
+			};
+			Proofs::<T, I>::insert(&claim, info);
+			Self::record_custody(&claim, &who, now, CustodyAction::Created);
+			// Emit the ClaimCreated event
+			Self::deposit_indexed(
+				Event::ClaimCreated { owner: who.clone(), claim, created_time: time },
+				&[claim],
+				&[&who],
+			);
+			Ok(())
+		}
+
+		// Revoke `claim` on behalf of `who`, who must own it alone and while it is not frozen.
+		fn revoke_as_owner(
+			who: T::AccountId,
+			claim: T::Hash,
+			reason: Option<BoundedVec<u8, T::MaxReasonLength>>,
+		) -> DispatchResult {
+			// Get the claim and ensure that it exists.
+			let info = Proofs::<T, I>::get(&claim).ok_or(Error::<T, I>::ClaimNotExists)?;
+
+			// Ensure that `who` is the sole owner of the claim.
+			Self::ensure_sole_owner(&claim, &info, &who)?;
+			ensure!(!info.frozen, Error::<T, I>::ClaimFrozen);
+
+			// Remove the claim, return the deposit to the owner and emit a ClaimRevoked event.
+			Self::do_revoke_claim(claim, info, Some(who), reason)
+		}
+
+		// Transfer `claim` from `who`, who must own it alone and while it is not frozen, to `dest`.
+		fn transfer_as_owner(
+			who: &T::AccountId,
+			claim: T::Hash,
+			dest: T::AccountId,
+		) -> DispatchResult {
+			// Get the claim and ensure that it exists.
+			let info = Proofs::<T, I>::get(&claim).ok_or(Error::<T, I>::ClaimNotExists)?;
+
+			// Ensure that `who` is the sole owner of the claim.
+			Self::ensure_sole_owner(&claim, &info, who)?;
+			ensure!(!info.frozen, Error::<T, I>::ClaimFrozen);
 
 			// Transfer the claim to the new owner.
-			Proofs::<T>::insert(
-				&bounded_claim,
-				(dest.clone(), frame_system::Pallet::<T>::block_number())
+			Self::do_transfer_claim(claim, info, dest)
+		}
+
+		// Hand `claim`, recorded as `info`, over from its owner to `to`, along with its deposit.
+		fn do_transfer_claim(
+			claim: T::Hash,
+			mut info: ClaimInfoOf<T, I>,
+			to: T::AccountId,
+		) -> DispatchResult {
+			let from = info.owner.clone();
+
+			// Move the claim between the owners' indexes.
+			if to != from {
+				Self::index_claim(&to, &claim)?;
+				Self::unindex_claim(&from, &claim);
+			}
+
+			// Move the deposit over to the new owner, who is now responsible for the claim.
+			T::Currency::repatriate_reserved_named(
+				&T::ReserveId::get(),
+				&from,
+				&to,
+				info.deposit,
+				BalanceStatus::Reserved,
+			)?;
+			if let Some(metadata) = ClaimMetadataOf::<T, I>::get(&claim) {
+				T::Currency::repatriate_reserved_named(
+					&T::ReserveId::get(),
+					&from,
+					&to,
+					metadata.deposit,
+					BalanceStatus::Reserved,
+				)?;
+			}
+
+			// Change the owner, keeping the block the claim was created at.
+			let now = frame_system::Pallet::<T>::block_number();
+			let time = Self::now_time();
+			let created_at = info.created_at;
+			info.owner = to.clone();
+			info.transferred_at = Some(now);
+			info.transferred_time = time;
+			Proofs::<T, I>::insert(&claim, info);
+
+			// Claims registered before custody was recorded have no history yet, so seed it with
+			// the owner handing them over.
+			if !ClaimHistory::<T, I>::contains_key(&claim) {
+				Self::record_custody(&claim, &from, created_at, CustodyAction::Created);
+			}
+			Self::record_custody(&claim, &to, now, CustodyAction::Transferred);
+
+			// Any outstanding offer, proposal or listing is void once the claim changes hands, and
+			// the new owner holds it alone.
+			PendingTransfers::<T, I>::remove(&claim);
+			Listings::<T, I>::remove(&claim);
+			CoOwners::<T, I>::remove(&claim);
+			Proposals::<T, I>::remove(&claim);
+
+			// Emit a ClaimTransferred event.
+			Self::deposit_indexed(
+				Event::ClaimTransferred {
+					from: from.clone(),
+					to: to.clone(),
+					claim,
+					transferred_time: time,
+				},
+				&[claim],
+				&[&from, &to],
 			);
+			Ok(())
+		}
+
+		// The current time in milliseconds since the Unix epoch, or `None` at genesis, before any
+		// time has been set.
+		fn now_time() -> Option<u64> {
+			if frame_system::Pallet::<T>::block_number().is_zero() {
+				return None
+			}
+			Some(T::TimeProvider::now().as_millis() as u64)
+		}
+
+		// Close the open custody record of `claim` and open one for `owner`, starting at `from`.
+		fn record_custody(
+			claim: &T::Hash,
+			owner: &T::AccountId,
+			from: T::BlockNumber,
+			action: CustodyAction,
+		) {
+			ClaimHistory::<T, I>::mutate(claim, |history| {
+				if let Some(last) = history.last_mut() {
+					last.to_block = Some(from);
+				}
+				if history.len() as u32 >= T::MaxHistoryLength::get() && !history.is_empty() {
+					history.remove(0);
+				}
+				let record = CustodyRecord {
+					owner: owner.clone(),
+					from_block: from,
+					to_block: None,
+					action,
+				};
+				// Only fails for a `MaxHistoryLength` of zero, in which case nothing is kept.
+				let _ = history.try_push(record);
+			});
+		}
+
+		// Ensure that `who` owns `claim`, recorded as `info`, alone or together with others.
+		fn ensure_owner(
+			claim: &T::Hash,
+			info: &ClaimInfoOf<T, I>,
+			who: &T::AccountId,
+		) -> DispatchResult {
+			let owns = info.owner == *who ||
+				CoOwners::<T, I>::get(claim).map_or(false, |co| co.owners.contains(who));
+			ensure!(owns, Error::<T, I>::NotClaimOwner);
+			Ok(())
+		}
 
-			// Emit a ClaimTransfered event.
-			Self::deposit_event(Event::ClaimTransfered(sender, dest, claim));
+		// Ensure that `who` owns `claim`, recorded as `info`, alone. Co-owned claims can only be
+		// revoked or transferred through proposals.
+		fn ensure_sole_owner(
+			claim: &T::Hash,
+			info: &ClaimInfoOf<T, I>,
+			who: &T::AccountId,
+		) -> DispatchResult {
+			Self::ensure_owner(claim, info, who)?;
+			ensure!(!CoOwners::<T, I>::contains_key(claim), Error::<T, I>::ClaimCoOwned);
+			Ok(())
+		}
+
+		// Remove the attestation `attester` made of `claim` and return its deposit.
+		fn remove_attestation_of(claim: &T::Hash, attester: &T::AccountId) -> DispatchResult {
+			Attestations::<T, I>::try_mutate_exists(claim, |attestations| -> DispatchResult {
+				let list = attestations.as_mut().ok_or(Error::<T, I>::NotAttested)?;
+				let index = list
+					.iter()
+					.position(|attestation| attestation.attester == *attester)
+					.ok_or(Error::<T, I>::NotAttested)?;
+				let attestation = list.remove(index);
+				if list.is_empty() {
+					*attestations = None;
+				}
+				T::Currency::unreserve_named(&T::ReserveId::get(), attester, attestation.deposit);
+				Ok(())
+			})
+		}
+
+		// Open a proposal by `who` to take `action` on the co-owned `claim`.
+		fn do_propose(
+			who: T::AccountId,
+			claim: T::Hash,
+			action: ProposalAction<T::AccountId>,
+		) -> DispatchResult {
+			let info = Proofs::<T, I>::get(&claim).ok_or(Error::<T, I>::ClaimNotExists)?;
+			Self::ensure_owner(&claim, &info, &who)?;
+			ensure!(CoOwners::<T, I>::contains_key(&claim), Error::<T, I>::ClaimNotCoOwned);
+			ensure!(!info.frozen, Error::<T, I>::ClaimFrozen);
+
+			// An expired proposal no longer blocks a new one.
+			let now = frame_system::Pallet::<T>::block_number();
+			if let Some(open) = Proposals::<T, I>::get(&claim) {
+				ensure!(now > open.expires_at, Error::<T, I>::ProposalExists);
+			}
+
+			let mut approvals = BoundedVec::default();
+			approvals.try_push(who.clone()).map_err(|_| Error::<T, I>::TooManyCoOwners)?;
+			let proposal = Proposal {
+				action: action.clone(),
+				approvals,
+				expires_at: now.saturating_add(T::ProposalDuration::get()),
+			};
+			Self::deposit_indexed(
+				Event::ProposalCreated { proposer: who.clone(), claim, action },
+				&[claim],
+				&[&who],
+			);
+			Self::try_execute(claim, info, proposal)
+		}
+
+		// Take the action of `proposal` on `claim`, recorded as `info`, if enough co-owners
+		// approved it, or keep it open otherwise.
+		fn try_execute(
+			claim: T::Hash,
+			info: ClaimInfoOf<T, I>,
+			proposal: Proposal<T, I>,
+		) -> DispatchResult {
+			let threshold = CoOwners::<T, I>::get(&claim).map_or(1, |co| co.threshold);
+			if (proposal.approvals.len() as u32) < threshold {
+				Proposals::<T, I>::insert(&claim, proposal);
+				return Ok(())
+			}
+
+			ensure!(!info.frozen, Error::<T, I>::ClaimFrozen);
+			Proposals::<T, I>::remove(&claim);
+			match proposal.action {
+				ProposalAction::Revoke => {
+					// The co-owner whose approval carried the proposal revokes the claim.
+					let revoker = proposal.approvals.last().cloned();
+					Self::do_revoke_claim(claim, info, revoker, None)?;
+				},
+				ProposalAction::Transfer(dest) => Self::do_transfer_claim(claim, info, dest)?,
+			}
+			Self::deposit_indexed(Event::ProposalExecuted { claim }, &[claim], &[]);
+			Ok(())
+		}
+
+		// Deposit `event` with the given claims and the `T::Hashing` hash of each of `accounts` as
+		// its topics.
+		fn deposit_indexed(event: Event<T, I>, claims: &[T::Hash], accounts: &[&T::AccountId]) {
+			let topics = claims
+				.iter()
+				.copied()
+				.chain(accounts.iter().map(|who| T::Hashing::hash_of(who)))
+				.collect::<Vec<_>>();
+			let event = <T as Config<I>>::RuntimeEvent::from(event);
+			frame_system::Pallet::<T>::deposit_event_indexed(&topics, event.into());
+		}
+
+		// Revoke `claim`, recorded as `info`, on behalf of `revoker`, leaving a tombstone in its
+		// place.
+		fn do_revoke_claim(
+			claim: T::Hash,
+			info: ClaimInfoOf<T, I>,
+			revoker: Option<T::AccountId>,
+			reason: Option<BoundedVec<u8, T::MaxReasonLength>>,
+		) -> DispatchResult {
+			Self::remove_claim(&claim, &info);
+			// The owner keeps part of the deposit reserved for the tombstone of a claim they
+			// revoked. Tombstones left by `ForceOrigin` are its own to clear.
+			let deposit = if revoker.is_some() {
+				T::TombstoneDeposit::get().min(info.deposit)
+			} else {
+				Zero::zero()
+			};
+			T::Currency::reserve_named(&T::ReserveId::get(), &info.owner, deposit)?;
+			let revoked_at = frame_system::Pallet::<T>::block_number();
+			Tombstones::<T, I>::insert(
+				&claim,
+				Tombstone { owner: info.owner.clone(), revoker, revoked_at, reason, deposit },
+			);
+			Self::deposit_indexed(
+				Event::ClaimRevoked { owner: info.owner.clone(), claim },
+				&[claim],
+				&[&info.owner],
+			);
+			Ok(())
+		}
+
+		// Bound the reason given for revoking a claim by `MaxReasonLength`.
+		fn bound_reason(
+			reason: Option<Vec<u8>>,
+		) -> Result<Option<BoundedVec<u8, T::MaxReasonLength>>, DispatchError> {
+			reason
+				.map(|reason| reason.try_into().map_err(|_| Error::<T, I>::ReasonTooLong.into()))
+				.transpose()
+		}
+
+		// Remove `claim`, recorded as `info`, along with everything kept about it, and return its
+		// deposit to the owner.
+		fn remove_claim(claim: &T::Hash, info: &ClaimInfoOf<T, I>) {
+			Proofs::<T, I>::remove(claim);
+			PendingTransfers::<T, I>::remove(claim);
+			CoOwners::<T, I>::remove(claim);
+			Proposals::<T, I>::remove(claim);
+			ClaimHistory::<T, I>::remove(claim);
+			Listings::<T, I>::remove(claim);
+			BatchRoots::<T, I>::remove(claim);
+
+			// Close the gap the claim leaves in its revision chain.
+			if let Some(revision) = ClaimRevisions::<T, I>::take(claim) {
+				if let Some(previous) = revision.previous {
+					Self::update_revision(&previous, |links| links.next = revision.next);
+				}
+				if let Some(next) = revision.next {
+					Self::update_revision(&next, |links| links.previous = revision.previous);
+				}
+			}
+			if let Some(expires_at) = info.expires_at {
+				ExpiryQueue::<T, I>::remove(expires_at, claim);
+			}
+			Self::unindex_claim(&info.owner, claim);
+
+			T::Currency::unreserve_named(&T::ReserveId::get(), &info.owner, info.deposit);
+			if let Some(metadata) = ClaimMetadataOf::<T, I>::take(claim) {
+				T::Currency::unreserve_named(&T::ReserveId::get(), &info.owner, metadata.deposit);
+			}
+			for attestation in Attestations::<T, I>::take(claim) {
+				T::Currency::unreserve_named(
+					&T::ReserveId::get(),
+					&attestation.attester,
+					attestation.deposit,
+				);
+			}
+		}
+
+		// Change the revision links of `claim`, dropping them once it is linked to no other claim.
+		fn update_revision(claim: &T::Hash, f: impl FnOnce(&mut ClaimRevision<T::Hash>)) {
+			ClaimRevisions::<T, I>::mutate_exists(claim, |revision| {
+				let mut links = revision.take().unwrap_or_default();
+				f(&mut links);
+				*revision =
+					Some(links).filter(|links| links.previous.is_some() || links.next.is_some());
+			});
+		}
+
+		// Attach `metadata` to `claim`, owned by `who`, reserving or returning the difference to
+		// the deposit held for any metadata it replaces.
+		fn do_set_metadata(
+			who: &T::AccountId,
+			claim: &T::Hash,
+			metadata: ClaimMetadata,
+		) -> DispatchResult {
+			let metadata = Self::bound_metadata(metadata)?;
+			let old_deposit =
+				ClaimMetadataOf::<T, I>::get(claim).map_or_else(Zero::zero, |old| old.deposit);
+			if metadata.deposit > old_deposit {
+				T::Currency::reserve_named(
+					&T::ReserveId::get(),
+					who,
+					metadata.deposit - old_deposit,
+				)?;
+			} else {
+				T::Currency::unreserve_named(
+					&T::ReserveId::get(),
+					who,
+					old_deposit - metadata.deposit,
+				);
+			}
+			ClaimMetadataOf::<T, I>::insert(claim, metadata);
+
+			Self::deposit_indexed(
+				Event::MetadataSet { owner: who.clone(), claim: *claim },
+				&[*claim],
+				&[who],
+			);
+			Ok(())
+		}
+
+		// Check each part of `metadata` against its limit, and work out the deposit it needs.
+		fn bound_metadata(metadata: ClaimMetadata) -> Result<MetadataInfo<T, I>, DispatchError> {
+			ensure!(
+				metadata.attributes.len() <= T::MaxAttributes::get() as usize,
+				Error::<T, I>::TooManyAttributes
+			);
+			let deposit = Self::metadata_deposit(
+				metadata.mime_type.len(),
+				metadata.title.len(),
+				metadata.uri.len(),
+				metadata.attributes.iter().map(|(key, value)| key.len() + value.len()).sum(),
+			);
+			let mut attributes = BoundedVec::default();
+			for (key, value) in metadata.attributes {
+				let key = key.try_into().map_err(|_| Error::<T, I>::AttributeKeyTooLong)?;
+				let value = value.try_into().map_err(|_| Error::<T, I>::AttributeValueTooLong)?;
+				attributes
+					.try_push((key, value))
+					.map_err(|_| Error::<T, I>::TooManyAttributes)?;
+			}
+			Ok(MetadataInfo {
+				mime_type: metadata
+					.mime_type
+					.try_into()
+					.map_err(|_| Error::<T, I>::MimeTypeTooLong)?,
+				title: metadata.title.try_into().map_err(|_| Error::<T, I>::TitleTooLong)?,
+				uri: metadata.uri.try_into().map_err(|_| Error::<T, I>::UriTooLong)?,
+				attributes,
+				deposit,
+			})
+		}
+
+		// Queue `claim` for removal at `expires_at`, which must be a future block.
+		fn schedule_expiry(claim: &T::Hash, expires_at: T::BlockNumber) -> DispatchResult {
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(expires_at > now, Error::<T, I>::ExpiryInPast);
+
+			ExpiryQueue::<T, I>::insert(expires_at, claim, ());
+			// Nothing expires before the current block, so pruning can start from it.
+			if !ExpiryCursor::<T, I>::exists() {
+				ExpiryCursor::<T, I>::put(now);
+			}
+			Ok(())
+		}
+
+		// Remove the claims that expired at or before `now`, oldest first, for as long as `limit`
+		// leaves room, and return the weight used, which never exceeds `limit`.
+		fn prune_expired(now: T::BlockNumber, limit: Weight) -> Weight {
+			let mut used = T::WeightInfo::expire_claims(0);
+			if used.any_gt(limit) {
+				return Weight::zero()
+			}
+			let mut cursor = match ExpiryCursor::<T, I>::get() {
+				Some(cursor) => cursor,
+				None => return T::DbWeight::get().reads(1),
+			};
+
+			let per_claim = T::WeightInfo::expire_claims(1).saturating_sub(used);
+			// Every block after the first costs one more read of the queue.
+			let per_block = T::DbWeight::get().reads(1);
+			let mut blocks = 0u32;
+			while cursor <= now {
+				if blocks > 0 {
+					if used.saturating_add(per_block).any_gt(limit) {
+						break
+					}
+					used.saturating_accrue(per_block);
+				}
+				blocks += 1;
+				let spare = limit.saturating_sub(used);
+				let fits = |spare: u64, cost: u64| spare.checked_div(cost).unwrap_or(u64::MAX);
+				let room = fits(spare.ref_time(), per_claim.ref_time())
+					.min(fits(spare.proof_size(), per_claim.proof_size()));
+				let room = usize::try_from(room).unwrap_or(usize::MAX);
+				let due =
+					ExpiryQueue::<T, I>::iter_key_prefix(cursor).take(room).collect::<Vec<_>>();
+				let exhausted = due.len() < room;
+				for claim in due {
+					ExpiryQueue::<T, I>::remove(cursor, &claim);
+					if let Some(info) = Proofs::<T, I>::get(&claim) {
+						Self::remove_claim(&claim, &info);
+						Self::deposit_indexed(
+							Event::ClaimExpired { owner: info.owner.clone(), claim },
+							&[claim],
+							&[&info.owner],
+						);
+					}
+					used.saturating_accrue(per_claim);
+				}
+				// Stay on this block until all of its claims are gone.
+				if !exhausted {
+					break
+				}
+				cursor.saturating_inc();
+			}
+			ExpiryCursor::<T, I>::put(cursor);
+			used
+		}
+
+		// Add `claim` to the index of `owner`, respecting `MaxClaimsPerAccount`.
+		fn index_claim(owner: &T::AccountId, claim: &T::Hash) -> DispatchResult {
+			ClaimCount::<T, I>::try_mutate(owner, |count| -> DispatchResult {
+				ensure!(*count < T::MaxClaimsPerAccount::get(), Error::<T, I>::TooManyClaims);
+				*count += 1;
+				Ok(())
+			})?;
+			ClaimsByOwner::<T, I>::insert(owner, claim, ());
+			Ok(())
+		}
+
+		// Remove `claim` from the index of `owner`.
+		fn unindex_claim(owner: &T::AccountId, claim: &T::Hash) {
+			ClaimsByOwner::<T, I>::remove(owner, claim);
+			ClaimCount::<T, I>::mutate_exists(owner, |count| {
+				*count = count.map(|c| c.saturating_sub(1)).filter(|c| *c > 0);
+			});
+		}
+
+		/// The details of `claim`, if it is registered.
+		pub fn claim_details(claim: T::Hash) -> Option<ClaimDetails<T::AccountId, T::BlockNumber>> {
+			Proofs::<T, I>::get(&claim).map(|info| {
+				let (co_owners, threshold) = CoOwners::<T, I>::get(&claim)
+					.map_or((Vec::new(), 1), |co| (co.owners.into_inner(), co.threshold));
+				ClaimDetails {
+					owner: info.owner,
+					created_at: info.created_at,
+					created_time: info.created_time,
+					transferred_at: info.transferred_at,
+					transferred_time: info.transferred_time,
+					algorithm: info.algorithm,
+					expires_at: info.expires_at,
+					frozen: info.frozen,
+					metadata: ClaimMetadataOf::<T, I>::get(&claim).map(Into::into),
+					co_owners,
+					threshold,
+					superseded: ClaimRevisions::<T, I>::get(&claim)
+						.map_or(false, |revision| revision.next.is_some()),
+				}
+			})
+		}
+
+		/// The custody records of `claim`, oldest first.
+		pub fn claim_history(claim: T::Hash) -> Vec<CustodyRecord<T::AccountId, T::BlockNumber>> {
+			ClaimHistory::<T, I>::get(&claim).into_inner()
+		}
+
+		/// What is kept of `claim` if it was revoked and not registered again since.
+		pub fn revocation_of(claim: T::Hash) -> Option<Revocation<T::AccountId, T::BlockNumber>> {
+			Tombstones::<T, I>::get(&claim).map(Into::into)
+		}
+
+		/// The revisions of `claim`, oldest first, including the claim itself, or none if it is not
+		/// registered.
+		pub fn revision_chain(claim: T::Hash) -> Vec<T::Hash> {
+			if !Proofs::<T, I>::contains_key(&claim) {
+				return Vec::new()
+			}
+			let previous = |claim: &T::Hash| ClaimRevisions::<T, I>::get(claim)?.previous;
+			let next = |claim: &T::Hash| ClaimRevisions::<T, I>::get(claim)?.next;
+
+			let mut chain: Vec<_> = sp_std::iter::successors(Some(claim), previous).collect();
+			chain.reverse();
+			chain.extend(sp_std::iter::successors(next(&claim), next));
+			chain
+		}
+
+		/// The attestations made of `claim`, oldest first.
+		pub fn claim_attestations(
+			claim: T::Hash,
+		) -> Vec<Attestation<T::AccountId, T::BlockNumber>> {
+			Attestations::<T, I>::get(&claim).into_iter().map(Into::into).collect()
+		}
+
+		/// Up to `limit` claims owned by `owner`, in storage order, starting after `start_after`.
+		///
+		/// Passing the last claim of a page as `start_after` returns the next page.
+		pub fn claims_of(
+			owner: &T::AccountId,
+			start_after: Option<T::Hash>,
+			limit: u32,
+		) -> Vec<T::Hash> {
+			let claims = match start_after {
+				Some(claim) => ClaimsByOwner::<T, I>::iter_key_prefix_from(
+					owner,
+					ClaimsByOwner::<T, I>::hashed_key_for(owner, claim),
+				),
+				None => ClaimsByOwner::<T, I>::iter_key_prefix(owner),
+			};
+			claims.take(limit as usize).collect()
+		}
+
+		/// Whether `leaf` is included in the registered batch `root`, given the sibling of each
+		/// node on the path from `leaf` up to `root`.
+		pub fn verify_inclusion(root: T::Hash, leaf: T::Hash, proof: &[T::Hash]) -> bool {
+			BatchRoots::<T, I>::get(&root).map_or(false, |leaf_count| {
+				// No path in a tree of `leaf_count` leaves is longer than its depth, and only the
+				// leaf of a single-leaf batch is its root.
+				let depth =
+					leaf_count.checked_next_power_of_two().map_or(32, |n| n.trailing_zeros());
+				(!proof.is_empty() || leaf_count == 1) &&
+					proof.len() <= depth as usize &&
+					Self::merkle_root(leaf, proof) == root
+			})
+		}
+
+		/// The root reached by hashing the leaf of the document digest `leaf` together with each
+		/// node of `proof` in turn.
+		pub fn merkle_root(leaf: T::Hash, proof: &[T::Hash]) -> T::Hash {
+			let leaf = T::ClaimHasher::hash_of(&(MERKLE_LEAF_PREFIX, leaf));
+			proof.iter().fold(leaf, |node, sibling| {
+				if node <= *sibling {
+					T::ClaimHasher::hash_of(&(MERKLE_NODE_PREFIX, node, sibling))
+				} else {
+					T::ClaimHasher::hash_of(&(MERKLE_NODE_PREFIX, sibling, node))
+				}
+			})
+		}
+
+		/// Check the invariants tying claims to their deposits, indexes and side records.
+		///
+		/// Claims are stored as their digest, so the `MaxClaimLength` bound on the claim data is
+		/// only checked on the way in.
+		#[cfg(any(feature = "try-runtime", test))]
+		pub fn do_try_state() -> Result<(), &'static str> {
+			let now = frame_system::Pallet::<T>::block_number();
+			let cursor = ExpiryCursor::<T, I>::get();
+			let mut deposits = BTreeMap::<T::AccountId, BalanceOf<T, I>>::new();
+			let mut counts = BTreeMap::<T::AccountId, u32>::new();
+
+			for (claim, info) in Proofs::<T, I>::iter() {
+				ensure!(info.created_at <= now, "a claim was created in the future");
+				if let Some(transferred_at) = info.transferred_at {
+					ensure!(
+						info.created_at <= transferred_at && transferred_at <= now,
+						"a claim was transferred before its creation or in the future"
+					);
+				}
+				if let (Some(created), Some(transferred)) =
+					(info.created_time, info.transferred_time)
+				{
+					ensure!(
+						created <= transferred,
+						"a claim was transferred before its creation time"
+					);
+				}
+				ensure!(
+					ClaimsByOwner::<T, I>::contains_key(&info.owner, &claim),
+					"a claim is not indexed under its owner"
+				);
+				if let Some(expires_at) = info.expires_at {
+					ensure!(
+						ExpiryQueue::<T, I>::contains_key(expires_at, &claim),
+						"an expiring claim is not queued for removal"
+					);
+					ensure!(
+						cursor.map_or(false, |cursor| cursor <= expires_at),
+						"an expiring claim is behind the expiry cursor"
+					);
+				}
+				if let Some(last) = ClaimHistory::<T, I>::get(&claim).last() {
+					ensure!(
+						last.owner == info.owner && last.to_block.is_none(),
+						"the custody history of a claim does not end with its owner"
+					);
+				}
+				let deposit = deposits.entry(info.owner.clone()).or_default();
+				*deposit = deposit.saturating_add(info.deposit);
+				*counts.entry(info.owner).or_default() += 1;
+			}
+
+			for (claim, co) in CoOwners::<T, I>::iter() {
+				let owner = Proofs::<T, I>::get(&claim)
+					.map(|info| info.owner)
+					.ok_or("the co-owners of a claim outlived it")?;
+				ensure!(
+					co.owners.contains(&owner),
+					"the owner of a claim is not among its co-owners"
+				);
+				ensure!(
+					co.threshold > 0 && co.threshold as usize <= co.owners.len(),
+					"the approval threshold of a claim cannot be met"
+				);
+			}
+			ensure!(
+				Proposals::<T, I>::iter_keys().all(|claim| CoOwners::<T, I>::contains_key(&claim)),
+				"a proposal is open on a claim that is not co-owned"
+			);
+
+			for (claim, tombstone) in Tombstones::<T, I>::iter() {
+				ensure!(
+					!Proofs::<T, I>::contains_key(&claim),
+					"a registered claim has a tombstone"
+				);
+				ensure!(tombstone.revoked_at <= now, "a claim was revoked in the future");
+				ensure!(
+					tombstone.revoker.is_some() || tombstone.deposit.is_zero(),
+					"a tombstone left by the force origin holds a deposit"
+				);
+				let deposit = deposits.entry(tombstone.owner).or_default();
+				*deposit = deposit.saturating_add(tombstone.deposit);
+			}
+
+			for (claim, revision) in ClaimRevisions::<T, I>::iter() {
+				ensure!(
+					Proofs::<T, I>::contains_key(&claim),
+					"the revision links of a claim outlived it"
+				);
+				ensure!(
+					revision.previous.is_some() || revision.next.is_some(),
+					"a claim has empty revision links"
+				);
+				if let Some(previous) = revision.previous {
+					ensure!(
+						ClaimRevisions::<T, I>::get(&previous).and_then(|links| links.next) ==
+							Some(claim),
+						"a claim is not superseded by the claim following it"
+					);
+				}
+				if let Some(next) = revision.next {
+					ensure!(
+						ClaimRevisions::<T, I>::get(&next).and_then(|links| links.previous) ==
+							Some(claim),
+						"a claim does not supersede the claim preceding it"
+					);
+				}
+			}
+
+			for (claim, attestations) in Attestations::<T, I>::iter() {
+				ensure!(
+					Proofs::<T, I>::contains_key(&claim),
+					"the attestations of a claim outlived it"
+				);
+				ensure!(
+					attestations.iter().all(|attestation| attestation.attested_at <= now),
+					"a claim was attested to in the future"
+				);
+				let mut attesters: Vec<_> =
+					attestations.iter().map(|attestation| &attestation.attester).collect();
+				attesters.sort();
+				attesters.dedup();
+				ensure!(
+					attesters.len() == attestations.len(),
+					"an account attested to a claim more than once"
+				);
+				for attestation in attestations {
+					let deposit = deposits.entry(attestation.attester).or_default();
+					*deposit = deposit.saturating_add(attestation.deposit);
+				}
+			}
+
+			for commitment in Commitments::<T, I>::iter_values() {
+				ensure!(commitment.committed_at <= now, "a commitment was made in the future");
+				let deposit = deposits.entry(commitment.who).or_default();
+				*deposit = deposit.saturating_add(commitment.deposit);
+			}
+
+			for (claim, metadata) in ClaimMetadataOf::<T, I>::iter() {
+				let owner = Proofs::<T, I>::get(&claim)
+					.map(|info| info.owner)
+					.ok_or("the metadata of a claim outlived it")?;
+				let deposit = deposits.entry(owner).or_default();
+				*deposit = deposit.saturating_add(metadata.deposit);
+			}
+
+			for (owner, claim, ()) in ClaimsByOwner::<T, I>::iter() {
+				ensure!(
+					Proofs::<T, I>::get(&claim).map_or(false, |info| info.owner == owner),
+					"an indexed claim is not owned by the account it is indexed under"
+				);
+			}
+			for (owner, count) in ClaimCount::<T, I>::iter() {
+				ensure!(
+					counts.get(&owner) == Some(&count),
+					"the claim count of an account does not match its claims"
+				);
+			}
+			ensure!(
+				ClaimCount::<T, I>::iter_keys().count() == counts.len(),
+				"an account owning claims has no claim count"
+			);
+			for (owner, deposit) in deposits {
+				ensure!(
+					T::Currency::reserved_balance_named(&T::ReserveId::get(), &owner) == deposit,
+					"the reserved balance of an account does not match its deposits"
+				);
+			}
+
+			for (expires_at, claim, ()) in ExpiryQueue::<T, I>::iter() {
+				ensure!(
+					Proofs::<T, I>::get(&claim)
+						.map_or(false, |info| info.expires_at == Some(expires_at)),
+					"a queued expiry does not belong to a claim"
+				);
+			}
+			ensure!(
+				PendingTransfers::<T, I>::iter_keys()
+					.all(|claim| Proofs::<T, I>::contains_key(&claim)),
+				"a transfer is offered for a missing claim"
+			);
+			ensure!(
+				Listings::<T, I>::iter_keys().all(|claim| {
+					Proofs::<T, I>::contains_key(&claim) && !CoOwners::<T, I>::contains_key(&claim)
+				}),
+				"a missing or co-owned claim is up for sale"
+			);
+			ensure!(
+				BatchRoots::<T, I>::iter_keys().all(|root| Proofs::<T, I>::contains_key(&root)),
+				"a batch root is not registered as a claim"
+			);
+			ensure!(
+				ClaimHistory::<T, I>::iter_keys().all(|claim| Proofs::<T, I>::contains_key(&claim)),
+				"a custody history outlived its claim"
+			);
+			Ok(())
+		}
+
+		/// The commitment made by `who` to `claim` with `salt`.
+		pub fn commitment_of(claim: &T::Hash, salt: &[u8; 32], who: &T::AccountId) -> T::Hash {
+			T::ClaimHasher::hash_of(&(claim, salt, who))
+		}
+
+		/// The deposit reserved for a claim, covering the bytes of its digest.
+		pub fn claim_deposit() -> BalanceOf<T, I> {
+			Self::deposit_for(T::Hash::max_encoded_len())
+		}
+
+		/// The deposit reserved for `len` bytes of claim data.
+		pub fn deposit_for(len: usize) -> BalanceOf<T, I> {
+			T::ClaimDepositPerByte::get()
+				.saturating_mul((len as u32).into())
+				.saturating_add(T::ClaimDepositBase::get())
+		}
+
+		/// The deposit reserved for claim metadata with a MIME type, title, URI and attributes of
+		/// the given numbers of bytes, each part charged at its own rate.
+		pub fn metadata_deposit(
+			mime_type: usize,
+			title: usize,
+			uri: usize,
+			attributes: usize,
+		) -> BalanceOf<T, I> {
+			let part = |rate: BalanceOf<T, I>, len: usize| rate.saturating_mul((len as u32).into());
+			T::MetadataDepositBase::get()
+				.saturating_add(part(T::MimeTypeDepositPerByte::get(), mime_type))
+				.saturating_add(part(T::TitleDepositPerByte::get(), title))
+				.saturating_add(part(T::UriDepositPerByte::get(), uri))
+				.saturating_add(part(T::AttributeDepositPerByte::get(), attributes))
+		}
+	}
+
+	impl<T: Config<I>, I: 'static> ProofOfExistence<T::AccountId, T::BlockNumber> for Pallet<T, I> {
+		type Claim = T::Hash;
+
+		fn owner_of(claim: &T::Hash) -> Option<T::AccountId> {
+			Proofs::<T, I>::get(claim).map(|info| info.owner)
+		}
+
+		fn exists(claim: &T::Hash) -> bool {
+			Proofs::<T, I>::contains_key(claim)
+		}
+
+		fn created_at(claim: &T::Hash) -> Option<T::BlockNumber> {
+			Proofs::<T, I>::get(claim).map(|info| info.created_at)
+		}
+
+		// Unlike dispatchables, these are not run in a storage layer of their own, so they open one
+		// to undo the writes made before a failing check.
+		fn do_create(who: &T::AccountId, claim: T::Hash) -> DispatchResult {
+			with_storage_layer(|| Self::do_create_claim(who.clone(), claim, None, None))
+		}
+
+		fn do_revoke(who: &T::AccountId, claim: &T::Hash) -> DispatchResult {
+			with_storage_layer(|| Self::revoke_as_owner(who.clone(), *claim, None))
+		}
 
-			Ok(().into())
+		fn do_transfer(from: &T::AccountId, claim: &T::Hash, to: &T::AccountId) -> DispatchResult {
+			with_storage_layer(|| Self::transfer_as_owner(from, *claim, to.clone()))
 		}
 	}
-}
\ No newline at end of file
+}
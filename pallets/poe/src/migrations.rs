@@ -0,0 +1,401 @@
+//! Storage migrations for the proof-of-existence pallet.
+
+use super::*;
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::traits::{Hash as HashT, Saturating},
+	storage_alias,
+	traits::{GetStorageVersion, NamedReservableCurrency, OnRuntimeUpgrade},
+};
+use sp_std::{collections::btree_map::BTreeMap, marker::PhantomData, vec::Vec};
+
+/// Migration from byte-keyed claims to digest-keyed claims.
+pub mod v1 {
+	use super::*;
+
+	/// The storage layout of storage version 0.
+	pub mod v0 {
+		use super::*;
+
+		#[storage_alias]
+		pub type Proofs<T: Config> = StorageMap<
+			Pallet<T>,
+			Blake2_128Concat,
+			BoundedVec<u8, <T as Config>::MaxClaimLength>,
+			(<T as frame_system::Config>::AccountId, <T as frame_system::Config>::BlockNumber),
+		>;
+	}
+
+	/// Re-keys every byte claim by its `T::ClaimHasher` digest, dropping the plaintext from state,
+	/// and indexes it under its owner. Owners holding more than `MaxClaimsPerAccount` claims keep
+	/// all of them, but cannot create new ones until they are back under the limit.
+	///
+	/// Byte claims were charged a deposit per byte of claim data. Any part of it exceeding the
+	/// deposit of a digest claim is returned to the owner.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+			if onchain_version != 0 {
+				log::info!(
+					target: "runtime::poe",
+					"MigrateToV1 should be removed, on-chain storage version is {:?}",
+					onchain_version,
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			// Old and new keys live under the same prefix, so drain everything before re-inserting.
+			let claims = v0::Proofs::<T>::drain().collect::<Vec<_>>();
+			let count = claims.len() as u64;
+			for (claim, (owner, block_number)) in claims {
+				let excess = Pallet::<T>::deposit_for(claim.len())
+					.saturating_sub(Pallet::<T>::claim_deposit());
+				T::Currency::unreserve_named(&T::ReserveId::get(), &owner, excess);
+				let claim = T::ClaimHasher::hash(&claim);
+				ClaimsByOwner::<T>::insert(&owner, &claim, ());
+				ClaimCount::<T>::mutate(&owner, |count| *count = count.saturating_add(1));
+				v2::v1::Proofs::<T>::insert(&claim, (owner, block_number));
+			}
+
+			StorageVersion::new(1).put::<Pallet<T>>();
+			log::info!(target: "runtime::poe", "Migrated {} claims to storage version 1", count);
+
+			// Draining reads and clears each claim, then its reserve, index and new entry are
+			// written.
+			T::DbWeight::get().reads_writes(count * 3 + 1, count * 5 + 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			let count = v0::Proofs::<T>::iter_keys().count() as u32;
+			Ok(count.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+			let count: u32 = Decode::decode(&mut &state[..])
+				.map_err(|_| "the state should have been generated by pre_upgrade")?;
+			ensure!(
+				v2::v1::Proofs::<T>::iter_keys().count() as u32 == count,
+				"the number of claims changed during the migration"
+			);
+			ensure!(
+				ClaimsByOwner::<T>::iter_keys().count() as u32 == count,
+				"not every claim was indexed under its owner"
+			);
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 1,
+				"the storage version was not updated"
+			);
+			Ok(())
+		}
+	}
+}
+
+/// Migration from claims recorded across several maps to a single [`ClaimInfo`] per claim.
+pub mod v2 {
+	use super::*;
+
+	/// The storage layout of storage version 1.
+	pub mod v1 {
+		use super::*;
+
+		#[storage_alias]
+		pub type Proofs<T: Config> = StorageMap<
+			Pallet<T>,
+			Blake2_128Concat,
+			<T as frame_system::Config>::Hash,
+			(<T as frame_system::Config>::AccountId, <T as frame_system::Config>::BlockNumber),
+		>;
+
+		#[storage_alias]
+		pub type ClaimAlgorithms<T: Config> = StorageMap<
+			Pallet<T>,
+			Blake2_128Concat,
+			<T as frame_system::Config>::Hash,
+			HashAlgorithm,
+		>;
+
+		#[storage_alias]
+		pub type LastTransferred<T: Config> = StorageMap<
+			Pallet<T>,
+			Blake2_128Concat,
+			<T as frame_system::Config>::Hash,
+			<T as frame_system::Config>::BlockNumber,
+		>;
+
+		#[storage_alias]
+		pub type ClaimExpiry<T: Config> = StorageMap<
+			Pallet<T>,
+			Blake2_128Concat,
+			<T as frame_system::Config>::Hash,
+			<T as frame_system::Config>::BlockNumber,
+		>;
+
+		#[storage_alias]
+		pub type FrozenClaims<T: Config> =
+			StorageMap<Pallet<T>, Blake2_128Concat, <T as frame_system::Config>::Hash, ()>;
+	}
+
+	/// Folds the owner and creation block of each claim, together with its algorithm, last
+	/// transfer, expiry and freeze, into a [`ClaimInfo`] recording the deposit reserved for it.
+	///
+	/// Claims created before deposits were taken, or charged for fewer bytes than a digest, hold
+	/// less than the deposit of a digest claim. Each claim therefore records the deposit of a
+	/// digest claim only as far as its owner still holds it in reserve beyond their other claims.
+	///
+	/// The creator of a claim is taken from its custody history, as long as that still starts at
+	/// the creation of the claim. Otherwise the current owner is recorded as its creator.
+	pub struct MigrateToV2<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+			if onchain_version != 1 {
+				log::info!(
+					target: "runtime::poe",
+					"MigrateToV2 should be removed, on-chain storage version is {:?}",
+					onchain_version,
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut count = 0u64;
+			// The reserve of each owner not yet recorded as the deposit of one of their claims.
+			let mut unrecorded = BTreeMap::<T::AccountId, BalanceOf<T>>::new();
+			// Old and new values live under the same key, so each claim is rewritten in place.
+			v3::v2::Proofs::<T>::translate::<(T::AccountId, T::BlockNumber), _>(
+				|claim, (owner, created_at)| {
+					count += 1;
+					let held = unrecorded.entry(owner.clone()).or_insert_with(|| {
+						T::Currency::reserved_balance_named(&T::ReserveId::get(), &owner)
+					});
+					let deposit = Pallet::<T>::claim_deposit().min(*held);
+					*held = held.saturating_sub(deposit);
+					let creator = ClaimHistory::<T>::get(&claim)
+						.first()
+						.filter(|record| record.action == CustodyAction::Created)
+						.map_or_else(|| owner.clone(), |record| record.owner.clone());
+					Some(v3::v2::ClaimInfo {
+						owner,
+						creator,
+						created_at,
+						transferred_at: v1::LastTransferred::<T>::take(&claim),
+						algorithm: v1::ClaimAlgorithms::<T>::take(&claim),
+						expires_at: v1::ClaimExpiry::<T>::take(&claim),
+						frozen: v1::FrozenClaims::<T>::take(&claim).is_some(),
+						deposit,
+					})
+				},
+			);
+
+			StorageVersion::new(2).put::<Pallet<T>>();
+			log::info!(target: "runtime::poe", "Migrated {} claims to storage version 2", count);
+
+			// Each claim, its history, four side maps and the reserve of its owner are read, then
+			// the claim is rewritten and the side maps cleared.
+			T::DbWeight::get().reads_writes(count * 7 + 1, count * 5 + 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			let count = v1::Proofs::<T>::iter_keys().count() as u32;
+			let frozen = v1::FrozenClaims::<T>::iter_keys().count() as u32;
+			Ok((count, frozen).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+			let (count, frozen): (u32, u32) = Decode::decode(&mut &state[..])
+				.map_err(|_| "the state should have been generated by pre_upgrade")?;
+			ensure!(
+				v3::v2::Proofs::<T>::iter_values().count() as u32 == count,
+				"the number of claims changed during the migration"
+			);
+			ensure!(
+				v3::v2::Proofs::<T>::iter_values().filter(|info| info.frozen).count() as u32 ==
+					frozen,
+				"the number of frozen claims changed during the migration"
+			);
+			ensure!(
+				v1::ClaimAlgorithms::<T>::iter_keys().next().is_none() &&
+					v1::LastTransferred::<T>::iter_keys().next().is_none() &&
+					v1::ClaimExpiry::<T>::iter_keys().next().is_none() &&
+					v1::FrozenClaims::<T>::iter_keys().next().is_none(),
+				"not every claim was folded into its record"
+			);
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 2,
+				"the storage version was not updated"
+			);
+			Ok(())
+		}
+	}
+}
+
+/// Migration recording the wall-clock time of creation and transfer in each [`ClaimInfo`].
+pub mod v3 {
+	use super::*;
+
+	/// The storage layout of storage version 2.
+	pub mod v2 {
+		use super::*;
+
+		/// Everything recorded about a registered claim in storage version 2.
+		#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+		pub struct ClaimInfo<AccountId, BlockNumber, Balance> {
+			pub owner: AccountId,
+			pub creator: AccountId,
+			pub created_at: BlockNumber,
+			pub transferred_at: Option<BlockNumber>,
+			pub algorithm: Option<HashAlgorithm>,
+			pub expires_at: Option<BlockNumber>,
+			pub frozen: bool,
+			pub deposit: Balance,
+		}
+
+		#[storage_alias]
+		pub type Proofs<T: Config> = StorageMap<
+			Pallet<T>,
+			Blake2_128Concat,
+			<T as frame_system::Config>::Hash,
+			ClaimInfo<
+				<T as frame_system::Config>::AccountId,
+				<T as frame_system::Config>::BlockNumber,
+				BalanceOf<T>,
+			>,
+		>;
+	}
+
+	/// Adds the times at which each claim was created and last transferred to its [`ClaimInfo`].
+	///
+	/// The time of a past block is not kept in state, so claims migrated this way have no
+	/// recorded times.
+	pub struct MigrateToV3<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV3<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+			if onchain_version != 2 {
+				log::info!(
+					target: "runtime::poe",
+					"MigrateToV3 should be removed, on-chain storage version is {:?}",
+					onchain_version,
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut count = 0u64;
+			Proofs::<T>::translate::<v2::ClaimInfo<T::AccountId, T::BlockNumber, BalanceOf<T>>, _>(
+				|_, old| {
+					count += 1;
+					Some(ClaimInfo {
+						owner: old.owner,
+						creator: old.creator,
+						created_at: old.created_at,
+						created_time: None,
+						transferred_at: old.transferred_at,
+						transferred_time: None,
+						algorithm: old.algorithm,
+						expires_at: old.expires_at,
+						frozen: old.frozen,
+						deposit: old.deposit,
+					})
+				},
+			);
+
+			StorageVersion::new(3).put::<Pallet<T>>();
+			log::info!(target: "runtime::poe", "Migrated {} claims to storage version 3", count);
+
+			// Each claim is read and rewritten in place.
+			T::DbWeight::get().reads_writes(count + 1, count + 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			let count = v2::Proofs::<T>::iter_keys().count() as u32;
+			Ok(count.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+			let count: u32 = Decode::decode(&mut &state[..])
+				.map_err(|_| "the state should have been generated by pre_upgrade")?;
+			ensure!(
+				Proofs::<T>::iter_values().count() as u32 == count,
+				"the number of claims changed during the migration"
+			);
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 3,
+				"the storage version was not updated"
+			);
+			Ok(())
+		}
+	}
+}
+
+/// Migration for an instance of the pallet added to a running chain.
+pub mod init {
+	use super::*;
+
+	/// Records the current storage version of an instance added to a running chain.
+	///
+	/// Such an instance starts out empty, in the current layout, but with no storage version on
+	/// chain. Without one, later migrations would take its storage for the oldest layout.
+	/// Instances that already have a storage version are left alone.
+	pub struct InitStorageVersion<T, I = ()>(PhantomData<(T, I)>);
+
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for InitStorageVersion<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T, I>::on_chain_storage_version();
+			if onchain_version != 0 {
+				log::info!(
+					target: "runtime::poe",
+					"InitStorageVersion should be removed, on-chain storage version is {:?}",
+					onchain_version,
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let current_version = Pallet::<T, I>::current_storage_version();
+			current_version.put::<Pallet<T, I>>();
+			log::info!(
+				target: "runtime::poe",
+				"Initialized the storage version to {:?}",
+				current_version,
+			);
+
+			T::DbWeight::get().reads_writes(1, 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			let onchain_version = Pallet::<T, I>::on_chain_storage_version();
+			if onchain_version == 0 {
+				ensure!(
+					Proofs::<T, I>::iter_keys().next().is_none(),
+					"an instance without a storage version should hold no claims"
+				);
+			}
+			Ok(onchain_version.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+			let onchain_version: StorageVersion = Decode::decode(&mut &state[..])
+				.map_err(|_| "the state should have been generated by pre_upgrade")?;
+			let expected = if onchain_version == 0 {
+				Pallet::<T, I>::current_storage_version()
+			} else {
+				onchain_version
+			};
+			ensure!(
+				Pallet::<T, I>::on_chain_storage_version() == expected,
+				"the storage version was not initialized"
+			);
+			Ok(())
+		}
+	}
+}
@@ -1,9 +1,15 @@
 use crate as pallet_poe;
-use frame_support::traits::{ConstU16, ConstU64, ConstU32};
+use frame_support::{
+	ord_parameter_types, parameter_types,
+	traits::{ConstU16, ConstU32, ConstU64, EitherOf, GenesisBuild},
+	weights::constants::RocksDbWeight,
+};
+use frame_system::{EnsureRoot, EnsureRootWithSuccess, EnsureSigned, EnsureSignedBy};
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, IdentityLookup},
+	Permill,
 };
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
@@ -17,7 +23,10 @@ frame_support::construct_runtime!(
 		UncheckedExtrinsic = UncheckedExtrinsic,
 	{
 		System: frame_system,
+		Balances: pallet_balances,
+		Timestamp: pallet_timestamp,
 		Poe: pallet_poe,
+		PoeReleases: pallet_poe::<Instance1>,
 	}
 );
 
@@ -25,7 +34,7 @@ impl frame_system::Config for Test {
 	type BaseCallFilter = frame_support::traits::Everything;
 	type BlockWeights = ();
 	type BlockLength = ();
-	type DbWeight = ();
+	type DbWeight = RocksDbWeight;
 	type RuntimeOrigin = RuntimeOrigin;
 	type RuntimeCall = RuntimeCall;
 	type Index = u64;
@@ -39,7 +48,7 @@ impl frame_system::Config for Test {
 	type BlockHashCount = ConstU64<250>;
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<u64>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
@@ -48,12 +57,138 @@ impl frame_system::Config for Test {
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
+parameter_types! {
+	pub static ExistentialDeposit: u64 = 1;
+}
+
+impl pallet_balances::Config for Test {
+	type MaxLocks = ();
+	type MaxReserves = ConstU32<50>;
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u64;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+}
+
+impl pallet_timestamp::Config for Test {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = ConstU64<5>;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const PoeReserveId: [u8; 8] = *b"poe/clam";
+	pub static AllowReregistration: bool = false;
+	pub const RootClaimOwner: u64 = 5;
+	pub const RoyaltyRate: Permill = Permill::from_percent(10);
+}
+
 impl pallet_poe::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type MaxClaimLength = ConstU32<512>;
+	type ClaimHasher = BlakeTwo256;
+	type MaxClaimsPerAccount = ConstU32<3>;
+	type OfferDuration = ConstU64<10>;
+	type MinRevealDelay = ConstU64<2>;
+	type CommitmentLifetime = ConstU64<10>;
+	type MaxHistoryLength = ConstU32<3>;
+	type MaxExpiriesPerBlock = ConstU32<2>;
+	type ForceOrigin = EnsureRoot<u64>;
+	type ClaimOrigin = EitherOf<EnsureSigned<u64>, EnsureRootWithSuccess<u64, RootClaimOwner>>;
+	type MaxCoOwners = ConstU32<3>;
+	type ProposalDuration = ConstU64<10>;
+	type MaxAttestations = ConstU32<2>;
+	type MaxNoteLength = ConstU32<8>;
+	type AttestationDeposit = ConstU64<3>;
+	type MaxReasonLength = ConstU32<8>;
+	type AllowReregistration = AllowReregistration;
+	type TombstoneDeposit = ConstU64<4>;
+	type TimeProvider = Timestamp;
+	type Currency = Balances;
+	type ReserveId = PoeReserveId;
+	type ClaimDepositBase = ConstU64<10>;
+	type ClaimDepositPerByte = ConstU64<1>;
+	type MaxMimeTypeLength = ConstU32<16>;
+	type MaxTitleLength = ConstU32<16>;
+	type MaxUriLength = ConstU32<32>;
+	type MaxAttributes = ConstU32<2>;
+	type MaxAttributeKeyLength = ConstU32<8>;
+	type MaxAttributeValueLength = ConstU32<16>;
+	type MetadataDepositBase = ConstU64<5>;
+	type MimeTypeDepositPerByte = ConstU64<1>;
+	type TitleDepositPerByte = ConstU64<2>;
+	type UriDepositPerByte = ConstU64<1>;
+	type AttributeDepositPerByte = ConstU64<1>;
+	type RoyaltyRate = RoyaltyRate;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const ReleaseReserveId: [u8; 8] = *b"poe/rels";
+}
+
+ord_parameter_types! {
+	pub const ReleaseAdmin: u64 = 3;
+}
+
+// A second, independent registry with its own limits, deposits and admin origin.
+impl pallet_poe::Config<pallet_poe::Instance1> for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type MaxClaimLength = ConstU32<16>;
+	type ClaimHasher = BlakeTwo256;
+	type MaxClaimsPerAccount = ConstU32<3>;
+	type OfferDuration = ConstU64<10>;
+	type MinRevealDelay = ConstU64<2>;
+	type CommitmentLifetime = ConstU64<10>;
+	type MaxHistoryLength = ConstU32<3>;
+	type MaxExpiriesPerBlock = ConstU32<2>;
+	type ForceOrigin = EnsureSignedBy<ReleaseAdmin, u64>;
+	type ClaimOrigin = EnsureSigned<u64>;
+	type MaxCoOwners = ConstU32<3>;
+	type ProposalDuration = ConstU64<10>;
+	type MaxAttestations = ConstU32<2>;
+	type MaxNoteLength = ConstU32<8>;
+	type AttestationDeposit = ConstU64<3>;
+	type MaxReasonLength = ConstU32<8>;
+	type AllowReregistration = AllowReregistration;
+	type TombstoneDeposit = ConstU64<4>;
+	type TimeProvider = Timestamp;
+	type Currency = Balances;
+	type ReserveId = ReleaseReserveId;
+	type ClaimDepositBase = ConstU64<20>;
+	type ClaimDepositPerByte = ConstU64<2>;
+	type MaxMimeTypeLength = ConstU32<16>;
+	type MaxTitleLength = ConstU32<16>;
+	type MaxUriLength = ConstU32<32>;
+	type MaxAttributes = ConstU32<2>;
+	type MaxAttributeKeyLength = ConstU32<8>;
+	type MaxAttributeValueLength = ConstU32<16>;
+	type MetadataDepositBase = ConstU64<5>;
+	type MimeTypeDepositPerByte = ConstU64<1>;
+	type TitleDepositPerByte = ConstU64<2>;
+	type UriDepositPerByte = ConstU64<1>;
+	type AttributeDepositPerByte = ConstU64<1>;
+	type RoyaltyRate = RoyaltyRate;
+	type WeightInfo = ();
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	frame_system::GenesisConfig::default().build_storage::<Test>().unwrap().into()
+	new_test_ext_with_claims(vec![])
+}
+
+// Build genesis storage with `claims` registered from the start.
+pub fn new_test_ext_with_claims(claims: Vec<(Vec<u8>, u64)>) -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	pallet_balances::GenesisConfig::<Test> { balances: vec![(1, 1_000), (2, 1_000), (3, 1_000)] }
+		.assimilate_storage(&mut t)
+		.unwrap();
+	pallet_poe::GenesisConfig::<Test> { claims, phantom: Default::default() }
+		.assimilate_storage(&mut t)
+		.unwrap();
+	t.into()
 }
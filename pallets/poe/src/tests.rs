@@ -1,60 +1,155 @@
-use crate::{mock::*, Error, Proofs};
-use frame_support::{assert_noop, assert_ok, BoundedVec};
+use crate::{
+	migrations::{
+		init::InitStorageVersion,
+		v1::{v0, MigrateToV1},
+		v2::{v1, MigrateToV2},
+		v3::{v2, MigrateToV3},
+	},
+	mock::*,
+	Attestation, Attestations, BatchRoots, ClaimDetails, ClaimHistory, ClaimInfo, ClaimMetadata,
+	ClaimMetadataOf, ClaimRevisions, ClaimsByOwner, CoOwners, Commitments, CustodyAction,
+	CustodyRecord, Error, Event, ExpiryQueue, HashAlgorithm, Instance1, Listings, PendingTransfers,
+	ProofOfExistence, Proofs, Revocation, Tombstones, WeightInfo,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{
+		Currency, Get, GetStorageVersion, Hooks, NamedReservableCurrency, OnRuntimeUpgrade,
+		ReservableCurrency, StorageVersion,
+	},
+	weights::{constants::RocksDbWeight, Weight},
+	BoundedVec,
+};
+use sp_core::H256;
+use sp_runtime::traits::{BadOrigin, BlakeTwo256, Hash};
+
+// The owner of `claim` and the block it was created at, if it is registered.
+fn owner_and_creation(claim: H256) -> Option<(u64, u64)> {
+	Proofs::<Test>::get(&claim).map(|info| (info.owner, info.created_at))
+}
 
 #[test]
 fn create_claim_works() {
 	new_test_ext().execute_with(|| {
 		let claim = vec![0, 1];
-		let bounded_claim = BoundedVec::try_from(claim.clone()).unwrap();
 
-		assert_ok!(Poe::create_claim(RuntimeOrigin::signed(1), claim));
+		assert_ok!(Poe::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
 		assert_eq!(
-			Proofs::<Test>::get(&bounded_claim),
+			owner_and_creation(BlakeTwo256::hash(&claim)),
 			Some((1, frame_system::Pallet::<Test>::block_number()))
 		);
 	});
 }
 
+#[test]
+fn create_claim_reserves_deposit() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+
+		assert_ok!(Poe::create_claim(RuntimeOrigin::signed(1), claim, None, None));
+		// Base deposit of 10 plus 1 for each of the 32 bytes of the digest.
+		assert_eq!(Balances::reserved_balance(&1), 42);
+		assert_eq!(Balances::free_balance(&1), 958);
+	});
+}
+
+#[test]
+fn create_claim_failed_with_insufficient_balance() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+
+		assert_noop!(
+			Poe::create_claim(RuntimeOrigin::signed(4), claim, None, None),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
 #[test]
 fn create_claim_failed_when_claim_too_long() {
 	new_test_ext().execute_with(|| {
 		let claim = Vec::from([1; 513]);
 
-		assert_noop!(Poe::create_claim(RuntimeOrigin::signed(1), claim), Error::<Test>::ClaimTooLong);
+		assert_noop!(
+			Poe::create_claim(RuntimeOrigin::signed(1), claim, None, None),
+			Error::<Test>::ClaimTooLong
+		);
 	});
 }
 
-
 #[test]
 fn create_claim_failed_when_claim_already_exists() {
 	new_test_ext().execute_with(|| {
 		let claim = vec![1, 2];
-		let _ = Poe::create_claim(RuntimeOrigin::signed(1), claim.clone());
+		let _ = Poe::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None);
 
 		assert_noop!(
-			Poe::create_claim(RuntimeOrigin::signed(1), claim),
+			Poe::create_claim(RuntimeOrigin::signed(1), claim, None, None),
 			Error::<Test>::ProofAlreadyExists
 		);
 	})
 }
 
 #[test]
-fn revoke_claim_works() {
+fn create_hashed_claim_works() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+
+		assert_ok!(Poe::create_hashed_claim(
+			RuntimeOrigin::signed(1),
+			claim,
+			Some(HashAlgorithm::Sha2_256)
+		));
+		assert_eq!(
+			owner_and_creation(claim),
+			Some((1, frame_system::Pallet::<Test>::block_number()))
+		);
+		assert_eq!(
+			Proofs::<Test>::get(&claim).and_then(|info| info.algorithm),
+			Some(HashAlgorithm::Sha2_256)
+		);
+		assert_eq!(Balances::reserved_balance(&1), 42);
+	});
+}
+
+#[test]
+fn create_hashed_claim_failed_when_digest_of_byte_claim_exists() {
 	new_test_ext().execute_with(|| {
 		let claim = vec![1, 2];
-		let _ = Poe::create_claim(RuntimeOrigin::signed(1), claim.clone());
+		let _ = Poe::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None);
+
+		assert_noop!(
+			Poe::create_hashed_claim(RuntimeOrigin::signed(2), BlakeTwo256::hash(&claim), None),
+			Error::<Test>::ProofAlreadyExists
+		);
+	})
+}
 
-		assert_ok!(Poe::revoke_claim(RuntimeOrigin::signed(1), claim));
+#[test]
+fn revoke_claim_works() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		let _ = Poe::create_hashed_claim(
+			RuntimeOrigin::signed(1),
+			claim,
+			Some(HashAlgorithm::Blake2_256),
+		);
+
+		assert_ok!(Poe::revoke_claim(RuntimeOrigin::signed(1), claim, None));
+		assert_eq!(Proofs::<Test>::get(&claim), None);
+		// `TombstoneDeposit` of the deposit stays reserved for the tombstone.
+		assert_eq!(Balances::reserved_balance(&1), 4);
+		assert_eq!(Balances::free_balance(&1), 996);
 	});
 }
 
 #[test]
 fn revoke_claim_failed_when_claim_not_exist() {
 	new_test_ext().execute_with(|| {
-		let claim = vec![1, 2];
+		let claim = H256::repeat_byte(1);
 
 		assert_noop!(
-			Poe::revoke_claim(RuntimeOrigin::signed(1), claim),
+			Poe::revoke_claim(RuntimeOrigin::signed(1), claim, None),
 			Error::<Test>::ClaimNotExists
 		);
 	});
@@ -63,11 +158,11 @@ fn revoke_claim_failed_when_claim_not_exist() {
 #[test]
 fn revoke_claim_failed_with_wrong_owner() {
 	new_test_ext().execute_with(|| {
-		let claim = vec![1, 2];
-		let _ = Poe::create_claim(RuntimeOrigin::signed(1), claim.clone());
+		let claim = H256::repeat_byte(1);
+		let _ = Poe::create_hashed_claim(RuntimeOrigin::signed(1), claim, None);
 
 		assert_noop!(
-			Poe::revoke_claim(RuntimeOrigin::signed(2), claim),
+			Poe::revoke_claim(RuntimeOrigin::signed(2), claim, None),
 			Error::<Test>::NotClaimOwner
 		);
 	});
@@ -76,23 +171,27 @@ fn revoke_claim_failed_with_wrong_owner() {
 #[test]
 fn transfer_claim_works() {
 	new_test_ext().execute_with(|| {
-		let claim = vec![1, 2];
-		let _ = Poe::create_claim(RuntimeOrigin::signed(1), claim.clone());
-		let bounded_claim = BoundedVec::try_from(claim.clone()).unwrap();
+		let claim = H256::repeat_byte(1);
+		System::set_block_number(1);
+		let _ = Poe::create_hashed_claim(RuntimeOrigin::signed(1), claim, None);
 
+		System::set_block_number(5);
 		assert_ok!(Poe::transfer_claim(RuntimeOrigin::signed(1), claim, 2));
-		assert_eq!(
-			Proofs::<Test>::get(&bounded_claim),
-			Some((2, frame_system::Pallet::<Test>::block_number()))
-		);
+		// The creation block is kept, the transfer is recorded separately.
+		assert_eq!(owner_and_creation(claim), Some((2, 1)));
+		assert_eq!(Proofs::<Test>::get(&claim).and_then(|info| info.transferred_at), Some(5));
+		// The deposit follows the claim to its new owner.
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(Balances::reserved_balance(&2), 42);
+		assert_eq!(Balances::free_balance(&2), 1_000);
 	});
 }
 
 #[test]
 fn transfer_claim_failed_with_wrong_owner() {
 	new_test_ext().execute_with(|| {
-		let claim = vec![1, 2];
-		let _ = Poe::create_claim(RuntimeOrigin::signed(1), claim.clone());
+		let claim = H256::repeat_byte(1);
+		let _ = Poe::create_hashed_claim(RuntimeOrigin::signed(1), claim, None);
 
 		assert_noop!(
 			Poe::transfer_claim(RuntimeOrigin::signed(2), claim, 3),
@@ -104,11 +203,1893 @@ fn transfer_claim_failed_with_wrong_owner() {
 #[test]
 fn transfer_claim_failed_when_claim_not_exist() {
 	new_test_ext().execute_with(|| {
-		let claim = vec![1, 2];
+		let claim = H256::repeat_byte(1);
 
 		assert_noop!(
 			Poe::transfer_claim(RuntimeOrigin::signed(1), claim, 2),
 			Error::<Test>::ClaimNotExists
 		);
 	});
-}
\ No newline at end of file
+}
+
+#[test]
+fn offer_and_accept_claim_works() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		System::set_block_number(1);
+		let _ = Poe::create_hashed_claim(RuntimeOrigin::signed(1), claim, None);
+
+		assert_ok!(Poe::offer_claim(RuntimeOrigin::signed(1), claim, 2));
+		// Nothing changes hands until the offer is accepted.
+		assert_eq!(owner_and_creation(claim), Some((1, 1)));
+		assert_eq!(PendingTransfers::<Test>::get(&claim).map(|offer| offer.expires_at), Some(11));
+
+		System::set_block_number(3);
+		assert_ok!(Poe::accept_claim(RuntimeOrigin::signed(2), claim));
+		assert_eq!(owner_and_creation(claim), Some((2, 1)));
+		assert_eq!(Proofs::<Test>::get(&claim).and_then(|info| info.transferred_at), Some(3));
+		assert_eq!(PendingTransfers::<Test>::get(&claim), None);
+		assert_eq!(Balances::reserved_balance(&2), 42);
+		assert_eq!(Poe::claim_count(2), 1);
+	});
+}
+
+#[test]
+fn offer_claim_failed_with_wrong_owner() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		let _ = Poe::create_hashed_claim(RuntimeOrigin::signed(1), claim, None);
+
+		assert_noop!(
+			Poe::offer_claim(RuntimeOrigin::signed(2), claim, 2),
+			Error::<Test>::NotClaimOwner
+		);
+	});
+}
+
+#[test]
+fn accept_claim_failed_for_other_account() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		let _ = Poe::create_hashed_claim(RuntimeOrigin::signed(1), claim, None);
+		assert_ok!(Poe::offer_claim(RuntimeOrigin::signed(1), claim, 2));
+
+		assert_noop!(
+			Poe::accept_claim(RuntimeOrigin::signed(3), claim),
+			Error::<Test>::NotOfferRecipient
+		);
+	});
+}
+
+#[test]
+fn accept_claim_failed_when_offer_expired() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		System::set_block_number(1);
+		let _ = Poe::create_hashed_claim(RuntimeOrigin::signed(1), claim, None);
+		assert_ok!(Poe::offer_claim(RuntimeOrigin::signed(1), claim, 2));
+
+		System::set_block_number(12);
+		assert_noop!(
+			Poe::accept_claim(RuntimeOrigin::signed(2), claim),
+			Error::<Test>::OfferExpired
+		);
+	});
+}
+
+#[test]
+fn cancel_offer_works() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		let _ = Poe::create_hashed_claim(RuntimeOrigin::signed(1), claim, None);
+		assert_ok!(Poe::offer_claim(RuntimeOrigin::signed(1), claim, 2));
+
+		assert_ok!(Poe::cancel_offer(RuntimeOrigin::signed(1), claim));
+		assert_noop!(
+			Poe::accept_claim(RuntimeOrigin::signed(2), claim),
+			Error::<Test>::NoPendingOffer
+		);
+	});
+}
+
+#[test]
+fn migrate_to_v1_rekeys_byte_claims() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![7; 100];
+		let bounded_claim = BoundedVec::try_from(claim.clone()).unwrap();
+		// A 100 byte claim was charged 110 under the per-byte deposit of version 0.
+		assert_ok!(Balances::reserve_named(&PoeReserveId::get(), &1, 110));
+		v0::Proofs::<Test>::insert(&bounded_claim, (1, 5));
+
+		MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(Poe::on_chain_storage_version(), 1);
+		assert_eq!(v1::Proofs::<Test>::get(BlakeTwo256::hash(&claim)), Some((1, 5)));
+		assert_eq!(v1::Proofs::<Test>::iter().count(), 1);
+		// Only the deposit of a digest claim stays reserved.
+		assert_eq!(Balances::reserved_balance(&1), 42);
+		assert_eq!(Poe::claims_of(&1, None, 10), vec![BlakeTwo256::hash(&claim)]);
+		assert_eq!(Poe::claim_count(1), 1);
+	});
+}
+
+#[test]
+fn claims_are_indexed_by_owner() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+
+		assert_ok!(Poe::create_hashed_claim(RuntimeOrigin::signed(1), claim, None));
+		assert!(ClaimsByOwner::<Test>::contains_key(1, claim));
+		assert_eq!(Poe::claim_count(1), 1);
+
+		assert_ok!(Poe::transfer_claim(RuntimeOrigin::signed(1), claim, 2));
+		assert!(!ClaimsByOwner::<Test>::contains_key(1, claim));
+		assert!(ClaimsByOwner::<Test>::contains_key(2, claim));
+		assert_eq!(Poe::claim_count(1), 0);
+		assert_eq!(Poe::claim_count(2), 1);
+
+		assert_ok!(Poe::revoke_claim(RuntimeOrigin::signed(2), claim, None));
+		assert!(!ClaimsByOwner::<Test>::contains_key(2, claim));
+		assert_eq!(Poe::claim_count(2), 0);
+	});
+}
+
+#[test]
+fn create_claim_failed_with_too_many_claims() {
+	new_test_ext().execute_with(|| {
+		for i in 1..=3 {
+			assert_ok!(Poe::create_hashed_claim(
+				RuntimeOrigin::signed(1),
+				H256::repeat_byte(i),
+				None
+			));
+		}
+
+		assert_noop!(
+			Poe::create_hashed_claim(RuntimeOrigin::signed(1), H256::repeat_byte(4), None),
+			Error::<Test>::TooManyClaims
+		);
+	});
+}
+
+#[test]
+fn transfer_claim_failed_when_dest_has_too_many_claims() {
+	new_test_ext().execute_with(|| {
+		for i in 1..=3 {
+			assert_ok!(Poe::create_hashed_claim(
+				RuntimeOrigin::signed(2),
+				H256::repeat_byte(i),
+				None
+			));
+		}
+		let claim = H256::repeat_byte(4);
+		let _ = Poe::create_hashed_claim(RuntimeOrigin::signed(1), claim, None);
+
+		assert_noop!(
+			Poe::transfer_claim(RuntimeOrigin::signed(1), claim, 2),
+			Error::<Test>::TooManyClaims
+		);
+	});
+}
+
+#[test]
+fn claim_details_works() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		assert_eq!(Poe::claim_details(claim), None);
+
+		let _ = Poe::create_hashed_claim(
+			RuntimeOrigin::signed(1),
+			claim,
+			Some(HashAlgorithm::Keccak256),
+		);
+		assert_eq!(
+			Poe::claim_details(claim),
+			Some(ClaimDetails {
+				owner: 1,
+				created_at: 0,
+				created_time: None,
+				transferred_at: None,
+				transferred_time: None,
+				algorithm: Some(HashAlgorithm::Keccak256),
+				expires_at: None,
+				frozen: false,
+				metadata: None,
+				co_owners: vec![],
+				threshold: 1,
+				superseded: false,
+			})
+		);
+	});
+}
+
+#[test]
+fn claims_of_paginates() {
+	new_test_ext().execute_with(|| {
+		for i in 1..=3 {
+			assert_ok!(Poe::create_hashed_claim(
+				RuntimeOrigin::signed(1),
+				H256::repeat_byte(i),
+				None
+			));
+		}
+
+		let first_page = Poe::claims_of(&1, None, 2);
+		assert_eq!(first_page.len(), 2);
+		let second_page = Poe::claims_of(&1, first_page.last().copied(), 2);
+		assert_eq!(second_page.len(), 1);
+
+		let mut all = [first_page, second_page].concat();
+		all.sort();
+		assert_eq!(all, (1..=3).map(H256::repeat_byte).collect::<Vec<_>>());
+	});
+}
+
+#[test]
+fn claim_history_records_custody() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		System::set_block_number(1);
+		assert_ok!(Poe::create_hashed_claim(RuntimeOrigin::signed(1), claim, None));
+		System::set_block_number(5);
+		assert_ok!(Poe::transfer_claim(RuntimeOrigin::signed(1), claim, 2));
+		System::set_block_number(9);
+		assert_ok!(Poe::transfer_claim(RuntimeOrigin::signed(2), claim, 3));
+
+		assert_eq!(
+			Poe::claim_history(claim),
+			vec![
+				CustodyRecord {
+					owner: 1,
+					from_block: 1,
+					to_block: Some(5),
+					action: CustodyAction::Created
+				},
+				CustodyRecord {
+					owner: 2,
+					from_block: 5,
+					to_block: Some(9),
+					action: CustodyAction::Transferred
+				},
+				CustodyRecord {
+					owner: 3,
+					from_block: 9,
+					to_block: None,
+					action: CustodyAction::Transferred
+				},
+			]
+		);
+	});
+}
+
+#[test]
+fn claim_history_drops_oldest_record_when_full() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		assert_ok!(Poe::create_hashed_claim(RuntimeOrigin::signed(1), claim, None));
+		assert_ok!(Poe::transfer_claim(RuntimeOrigin::signed(1), claim, 2));
+		assert_ok!(Poe::transfer_claim(RuntimeOrigin::signed(2), claim, 3));
+		assert_ok!(Poe::transfer_claim(RuntimeOrigin::signed(3), claim, 1));
+
+		let owners = Poe::claim_history(claim).into_iter().map(|r| r.owner).collect::<Vec<_>>();
+		assert_eq!(owners, vec![2, 3, 1]);
+	});
+}
+
+#[test]
+fn claim_history_is_seeded_for_claims_without_one() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		assert_ok!(Poe::create_hashed_claim(RuntimeOrigin::signed(1), claim, None));
+		ClaimHistory::<Test>::remove(&claim);
+
+		System::set_block_number(5);
+		assert_ok!(Poe::transfer_claim(RuntimeOrigin::signed(1), claim, 2));
+		let history = Poe::claim_history(claim);
+		assert_eq!(history.len(), 2);
+		assert_eq!((history[0].owner, history[0].from_block, history[0].to_block), (1, 0, Some(5)));
+	});
+}
+
+#[test]
+fn revoke_claim_clears_history() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		assert_ok!(Poe::create_hashed_claim(RuntimeOrigin::signed(1), claim, None));
+		assert_ok!(Poe::revoke_claim(RuntimeOrigin::signed(1), claim, None));
+		assert!(!ClaimHistory::<Test>::contains_key(&claim));
+		assert!(Poe::claim_history(claim).is_empty());
+	});
+}
+
+// The leaf of a document digest in a merkle batch.
+fn merkle_leaf(digest: H256) -> H256 {
+	BlakeTwo256::hash_of(&(0u8, digest))
+}
+
+// The parent of two nodes of a merkle batch.
+fn merkle_parent(a: H256, b: H256) -> H256 {
+	if a <= b {
+		BlakeTwo256::hash_of(&(1u8, a, b))
+	} else {
+		BlakeTwo256::hash_of(&(1u8, b, a))
+	}
+}
+
+#[test]
+fn create_batch_root_works() {
+	new_test_ext().execute_with(|| {
+		let root = H256::repeat_byte(1);
+		assert_ok!(Poe::create_batch_root(RuntimeOrigin::signed(1), root, 3));
+
+		assert_eq!(BatchRoots::<Test>::get(&root), Some(3));
+		assert_eq!(owner_and_creation(root), Some((1, 0)));
+		assert_eq!(Balances::reserved_balance(&1), 42);
+
+		assert_ok!(Poe::revoke_claim(RuntimeOrigin::signed(1), root, None));
+		assert!(!BatchRoots::<Test>::contains_key(&root));
+	});
+}
+
+#[test]
+fn create_batch_root_failed_when_batch_is_empty() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Poe::create_batch_root(RuntimeOrigin::signed(1), H256::repeat_byte(1), 0),
+			Error::<Test>::EmptyBatch
+		);
+	});
+}
+
+#[test]
+fn verify_inclusion_works() {
+	new_test_ext().execute_with(|| {
+		let [a, b, c] = [1, 2, 3].map(H256::repeat_byte);
+		let [la, lb, lc] = [a, b, c].map(merkle_leaf);
+		let ab = merkle_parent(la, lb);
+		let root = merkle_parent(ab, lc);
+		assert_ok!(Poe::create_batch_root(RuntimeOrigin::signed(1), root, 3));
+
+		assert!(Poe::verify_inclusion(root, a, &[lb, lc]));
+		assert!(Poe::verify_inclusion(root, b, &[la, lc]));
+		// The unpaired leaf is carried up unchanged, so its path is shorter.
+		assert!(Poe::verify_inclusion(root, c, &[ab]));
+
+		assert!(!Poe::verify_inclusion(root, H256::repeat_byte(4), &[lb, lc]));
+		assert!(!Poe::verify_inclusion(root, a, &[lc, lb]));
+	});
+}
+
+#[test]
+fn verify_inclusion_rejects_nodes_passed_as_leaves() {
+	new_test_ext().execute_with(|| {
+		let [a, b, c] = [1, 2, 3].map(H256::repeat_byte);
+		let ab = merkle_parent(merkle_leaf(a), merkle_leaf(b));
+		let root = merkle_parent(ab, merkle_leaf(c));
+		assert_ok!(Poe::create_batch_root(RuntimeOrigin::signed(1), root, 3));
+
+		// Neither the root itself nor an inner node is a leaf of the batch.
+		assert!(!Poe::verify_inclusion(root, root, &[]));
+		assert!(!Poe::verify_inclusion(root, ab, &[merkle_leaf(c)]));
+	});
+}
+
+#[test]
+fn verify_inclusion_takes_an_empty_proof_for_single_leaf_batches_only() {
+	new_test_ext().execute_with(|| {
+		let [a, b] = [1, 2].map(H256::repeat_byte);
+		let single = merkle_leaf(a);
+		assert_ok!(Poe::create_batch_root(RuntimeOrigin::signed(1), single, 1));
+		assert!(Poe::verify_inclusion(single, a, &[]));
+
+		let pair = merkle_leaf(b);
+		assert_ok!(Poe::create_batch_root(RuntimeOrigin::signed(1), pair, 2));
+		assert!(!Poe::verify_inclusion(pair, b, &[]));
+	});
+}
+
+#[test]
+fn verify_inclusion_rejects_unregistered_roots_and_long_proofs() {
+	new_test_ext().execute_with(|| {
+		let [a, b, c] = [1, 2, 3].map(H256::repeat_byte);
+		let [la, lb, lc] = [a, b, c].map(merkle_leaf);
+		let root = merkle_parent(merkle_parent(la, lb), lc);
+		assert!(!Poe::verify_inclusion(root, a, &[lb, lc]));
+
+		// A batch of two leaves has no paths of length two.
+		assert_ok!(Poe::create_batch_root(RuntimeOrigin::signed(1), root, 2));
+		assert!(!Poe::verify_inclusion(root, a, &[lb, lc]));
+	});
+}
+
+#[test]
+fn expired_claim_is_removed_and_refunded() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		let digest = BlakeTwo256::hash(&claim);
+		System::set_block_number(1);
+		assert_ok!(Poe::create_claim(RuntimeOrigin::signed(1), claim, Some(5), None));
+		assert_eq!(Poe::claim_details(digest).and_then(|details| details.expires_at), Some(5));
+
+		System::set_block_number(4);
+		Poe::on_initialize(4);
+		assert!(Proofs::<Test>::contains_key(&digest));
+
+		System::set_block_number(5);
+		Poe::on_initialize(5);
+		assert!(!Proofs::<Test>::contains_key(&digest));
+		assert_eq!(Poe::claim_count(1), 0);
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		System::assert_last_event(Event::<Test>::ClaimExpired { owner: 1, claim: digest }.into());
+	});
+}
+
+#[test]
+fn create_claim_failed_when_expiry_in_past() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(5);
+		assert_noop!(
+			Poe::create_claim(RuntimeOrigin::signed(1), vec![0, 1], Some(5), None),
+			Error::<Test>::ExpiryInPast
+		);
+	});
+}
+
+#[test]
+fn expiries_over_the_block_limit_are_removed_when_idle() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		for i in 0..3 {
+			assert_ok!(Poe::create_claim(RuntimeOrigin::signed(1), vec![i], Some(5), None));
+		}
+
+		System::set_block_number(5);
+		// `MaxExpiriesPerBlock` is two, so one claim is left over.
+		Poe::on_initialize(5);
+		assert_eq!(Poe::claim_count(1), 1);
+		assert_eq!(ExpiryQueue::<Test>::iter_key_prefix(5).count(), 1);
+
+		Poe::on_idle(5, Weight::MAX);
+		assert_eq!(Poe::claim_count(1), 0);
+		assert_eq!(ExpiryQueue::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn pruning_expiries_stays_within_the_weight_given() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		for i in 0..3 {
+			let expires_at = 5 + i as u64;
+			assert_ok!(Poe::create_claim(
+				RuntimeOrigin::signed(1),
+				vec![i],
+				Some(expires_at),
+				None
+			));
+		}
+
+		// After a long gap, only a few of the blocks since the last pruning fit in the weight.
+		System::set_block_number(1_000);
+		assert_eq!(Poe::on_idle(1_000, Weight::zero()), Weight::zero());
+		let remaining_weight =
+			<() as WeightInfo>::expire_claims(1).saturating_add(RocksDbWeight::get().reads(10));
+		assert!(Poe::on_idle(1_000, remaining_weight).all_lte(remaining_weight));
+		assert_eq!(Poe::claim_count(1), 2);
+
+		// `MaxExpiriesPerBlock` is two.
+		assert!(Poe::on_initialize(1_000).all_lte(<() as WeightInfo>::expire_claims(2)));
+		Poe::on_idle(1_000, Weight::MAX);
+		assert_eq!(Poe::claim_count(1), 0);
+	});
+}
+
+#[test]
+fn renew_claim_works() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		let digest = BlakeTwo256::hash(&claim);
+		System::set_block_number(1);
+		assert_ok!(Poe::create_claim(RuntimeOrigin::signed(1), claim, Some(5), None));
+
+		assert_ok!(Poe::renew_claim(RuntimeOrigin::signed(1), digest, 10));
+		assert_eq!(Proofs::<Test>::get(&digest).and_then(|info| info.expires_at), Some(10));
+
+		System::set_block_number(5);
+		Poe::on_initialize(5);
+		assert!(Proofs::<Test>::contains_key(&digest));
+
+		System::set_block_number(10);
+		Poe::on_initialize(10);
+		assert!(!Proofs::<Test>::contains_key(&digest));
+	});
+}
+
+#[test]
+fn renew_claim_failed_without_later_expiry() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		let digest = BlakeTwo256::hash(&claim);
+		let permanent = H256::repeat_byte(1);
+		System::set_block_number(1);
+		assert_ok!(Poe::create_claim(RuntimeOrigin::signed(1), claim, Some(5), None));
+		assert_ok!(Poe::create_hashed_claim(RuntimeOrigin::signed(1), permanent, None));
+
+		assert_noop!(
+			Poe::renew_claim(RuntimeOrigin::signed(1), digest, 5),
+			Error::<Test>::ExpiryNotExtended
+		);
+		assert_noop!(
+			Poe::renew_claim(RuntimeOrigin::signed(2), digest, 10),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_noop!(
+			Poe::renew_claim(RuntimeOrigin::signed(1), permanent, 10),
+			Error::<Test>::ClaimNotExpiring
+		);
+	});
+}
+
+#[test]
+fn frozen_claim_cannot_be_renewed() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		let digest = BlakeTwo256::hash(&claim);
+		System::set_block_number(1);
+		assert_ok!(Poe::create_claim(RuntimeOrigin::signed(1), claim, Some(5), None));
+		assert_ok!(Poe::freeze_claim(RuntimeOrigin::root(), digest));
+
+		assert_noop!(
+			Poe::renew_claim(RuntimeOrigin::signed(1), digest, 10),
+			Error::<Test>::ClaimFrozen
+		);
+
+		assert_ok!(Poe::unfreeze_claim(RuntimeOrigin::root(), digest));
+		assert_ok!(Poe::renew_claim(RuntimeOrigin::signed(1), digest, 10));
+	});
+}
+
+#[test]
+fn revoke_claim_removes_it_from_expiry_queue() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		let digest = BlakeTwo256::hash(&claim);
+		System::set_block_number(1);
+		assert_ok!(Poe::create_claim(RuntimeOrigin::signed(1), claim, Some(5), None));
+
+		assert_ok!(Poe::revoke_claim(RuntimeOrigin::signed(1), digest, None));
+		assert!(!ExpiryQueue::<Test>::contains_key(5, &digest));
+	});
+}
+
+#[test]
+fn frozen_claim_cannot_be_transferred_or_revoked() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		assert_ok!(Poe::create_hashed_claim(RuntimeOrigin::signed(1), claim, None));
+		assert_ok!(Poe::freeze_claim(RuntimeOrigin::root(), claim));
+		assert_eq!(Poe::claim_details(claim).map(|details| details.frozen), Some(true));
+
+		assert_noop!(
+			Poe::transfer_claim(RuntimeOrigin::signed(1), claim, 2),
+			Error::<Test>::ClaimFrozen
+		);
+		assert_noop!(
+			Poe::revoke_claim(RuntimeOrigin::signed(1), claim, None),
+			Error::<Test>::ClaimFrozen
+		);
+		assert_noop!(
+			Poe::offer_claim(RuntimeOrigin::signed(1), claim, 2),
+			Error::<Test>::ClaimFrozen
+		);
+
+		assert_ok!(Poe::unfreeze_claim(RuntimeOrigin::root(), claim));
+		assert_ok!(Poe::transfer_claim(RuntimeOrigin::signed(1), claim, 2));
+	});
+}
+
+#[test]
+fn freeze_claim_requires_force_origin() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		assert_ok!(Poe::create_hashed_claim(RuntimeOrigin::signed(1), claim, None));
+
+		assert_noop!(Poe::freeze_claim(RuntimeOrigin::signed(1), claim), BadOrigin);
+		assert_noop!(Poe::force_revoke(RuntimeOrigin::signed(2), claim, None), BadOrigin);
+		assert_noop!(Poe::force_transfer(RuntimeOrigin::signed(2), claim, 2), BadOrigin);
+		assert_noop!(
+			Poe::unfreeze_claim(RuntimeOrigin::root(), claim),
+			Error::<Test>::ClaimNotFrozen
+		);
+	});
+}
+
+#[test]
+fn force_revoke_works_on_frozen_claim() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		assert_ok!(Poe::create_hashed_claim(RuntimeOrigin::signed(1), claim, None));
+		assert_ok!(Poe::freeze_claim(RuntimeOrigin::root(), claim));
+
+		assert_ok!(Poe::force_revoke(RuntimeOrigin::root(), claim, None));
+		assert!(!Proofs::<Test>::contains_key(&claim));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+	});
+}
+
+#[test]
+fn force_transfer_works_on_frozen_claim() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		assert_ok!(Poe::create_hashed_claim(RuntimeOrigin::signed(1), claim, None));
+		assert_ok!(Poe::freeze_claim(RuntimeOrigin::root(), claim));
+
+		assert_ok!(Poe::force_transfer(RuntimeOrigin::root(), claim, 2));
+		assert_eq!(owner_and_creation(claim), Some((2, 0)));
+		assert_eq!(Balances::reserved_balance(&2), 42);
+		assert_eq!(Proofs::<Test>::get(&claim).map(|info| info.frozen), Some(true));
+	});
+}
+
+#[test]
+fn migrate_to_v2_folds_claims_into_records() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(1).put::<Poe>();
+		let [created, transferred] = [1, 2].map(H256::repeat_byte);
+		v1::Proofs::<Test>::insert(&created, (1, 5));
+		v1::ClaimAlgorithms::<Test>::insert(&created, HashAlgorithm::Keccak256);
+		v1::ClaimExpiry::<Test>::insert(&created, 20);
+		v1::Proofs::<Test>::insert(&transferred, (2, 3));
+		v1::LastTransferred::<Test>::insert(&transferred, 7);
+		v1::FrozenClaims::<Test>::insert(&transferred, ());
+		// The second claim was charged for fewer bytes than a digest.
+		assert_ok!(Balances::reserve_named(&PoeReserveId::get(), &1, 42));
+		assert_ok!(Balances::reserve_named(&PoeReserveId::get(), &2, 12));
+		ClaimHistory::<Test>::insert(
+			&transferred,
+			BoundedVec::try_from(vec![
+				CustodyRecord {
+					owner: 3,
+					from_block: 3,
+					to_block: Some(7),
+					action: CustodyAction::Created,
+				},
+				CustodyRecord {
+					owner: 2,
+					from_block: 7,
+					to_block: None,
+					action: CustodyAction::Transferred,
+				},
+			])
+			.unwrap(),
+		);
+
+		MigrateToV2::<Test>::on_runtime_upgrade();
+
+		assert_eq!(Poe::on_chain_storage_version(), 2);
+		assert_eq!(
+			v2::Proofs::<Test>::get(&created),
+			Some(v2::ClaimInfo {
+				owner: 1,
+				creator: 1,
+				created_at: 5,
+				transferred_at: None,
+				algorithm: Some(HashAlgorithm::Keccak256),
+				expires_at: Some(20),
+				frozen: false,
+				deposit: 42,
+			})
+		);
+		assert_eq!(
+			v2::Proofs::<Test>::get(&transferred),
+			Some(v2::ClaimInfo {
+				owner: 2,
+				creator: 3,
+				created_at: 3,
+				transferred_at: Some(7),
+				algorithm: None,
+				expires_at: None,
+				frozen: true,
+				deposit: 12,
+			})
+		);
+		assert_eq!(v1::ClaimExpiry::<Test>::iter().count(), 0);
+		assert_eq!(v1::FrozenClaims::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn revoke_claim_returns_recorded_deposit() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		assert_ok!(Poe::create_hashed_claim(RuntimeOrigin::signed(1), claim, None));
+		// A deposit taken under an older, smaller rate is returned as it was recorded.
+		assert_eq!(Balances::unreserve_named(&PoeReserveId::get(), &1, 2), 0);
+		Proofs::<Test>::mutate(&claim, |info| info.as_mut().unwrap().deposit = 40);
+
+		assert_ok!(Poe::transfer_claim(RuntimeOrigin::signed(1), claim, 2));
+		assert_eq!(Balances::reserved_balance(&2), 40);
+		assert_ok!(Poe::revoke_claim(RuntimeOrigin::signed(2), claim, None));
+		assert_eq!(Balances::reserved_balance(&2), 4);
+		assert_eq!(Balances::free_balance(&2), 996);
+	});
+}
+
+#[test]
+fn genesis_config_registers_claims() {
+	new_test_ext_with_claims(vec![(vec![0, 1], 1), (vec![2, 3], 2)]).execute_with(|| {
+		assert_eq!(owner_and_creation(BlakeTwo256::hash(&[0, 1])), Some((1, 0)));
+		assert_eq!(owner_and_creation(BlakeTwo256::hash(&[2, 3])), Some((2, 0)));
+		assert_eq!(Poe::claim_count(1), 1);
+		assert_eq!(Balances::reserved_balance(&2), 42);
+	});
+}
+
+#[test]
+#[should_panic(expected = "duplicate genesis claim")]
+fn genesis_config_rejects_duplicate_claims() {
+	new_test_ext_with_claims(vec![(vec![0, 1], 1), (vec![0, 1], 2)]);
+}
+
+#[test]
+#[should_panic(expected = "genesis claim exceeds `MaxClaimLength`")]
+fn genesis_config_rejects_claims_too_long() {
+	new_test_ext_with_claims(vec![(vec![1; 513], 1)]);
+}
+
+#[test]
+fn try_state_holds_after_claims_change_hands() {
+	new_test_ext_with_claims(vec![(vec![0, 1], 1)]).execute_with(|| {
+		System::set_block_number(1);
+		let claim = H256::repeat_byte(1);
+		assert_ok!(Poe::create_claim(RuntimeOrigin::signed(1), vec![2, 3], Some(5), None));
+		assert_ok!(Poe::create_batch_root(RuntimeOrigin::signed(2), claim, 4));
+		assert_ok!(Poe::offer_claim(RuntimeOrigin::signed(2), claim, 3));
+		assert_ok!(Poe::transfer_claim(RuntimeOrigin::signed(1), BlakeTwo256::hash(&[0, 1]), 2));
+		assert_ok!(Poe::do_try_state());
+
+		System::set_block_number(5);
+		Poe::on_initialize(5);
+		assert_ok!(Poe::accept_claim(RuntimeOrigin::signed(3), claim));
+		assert_ok!(Poe::do_try_state());
+	});
+}
+
+#[test]
+fn try_state_detects_corrupted_index() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		assert_ok!(Poe::create_hashed_claim(RuntimeOrigin::signed(1), claim, None));
+
+		ClaimsByOwner::<Test>::remove(1, claim);
+		ClaimsByOwner::<Test>::insert(2, claim, ());
+		assert_eq!(Poe::do_try_state(), Err("a claim is not indexed under its owner"));
+	});
+}
+
+#[test]
+fn try_state_detects_unbacked_deposit() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		assert_ok!(Poe::create_hashed_claim(RuntimeOrigin::signed(1), claim, None));
+
+		assert_eq!(Balances::unreserve_named(&PoeReserveId::get(), &1, 2), 0);
+		assert_eq!(
+			Poe::do_try_state(),
+			Err("the reserved balance of an account does not match its deposits")
+		);
+	});
+}
+
+#[test]
+fn claims_record_creation_and_transfer_times() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Timestamp::set_timestamp(1_000);
+		let claim = H256::repeat_byte(1);
+		assert_ok!(Poe::create_hashed_claim(RuntimeOrigin::signed(1), claim, None));
+		System::assert_last_event(
+			Event::<Test>::ClaimCreated { owner: 1, claim, created_time: Some(1_000) }.into(),
+		);
+
+		System::set_block_number(2);
+		Timestamp::set_timestamp(7_000);
+		assert_ok!(Poe::transfer_claim(RuntimeOrigin::signed(1), claim, 2));
+		System::assert_last_event(
+			Event::<Test>::ClaimTransferred {
+				from: 1,
+				to: 2,
+				claim,
+				transferred_time: Some(7_000),
+			}
+			.into(),
+		);
+
+		let details = Poe::claim_details(claim).unwrap();
+		assert_eq!((details.created_at, details.created_time), (1, Some(1_000)));
+		assert_eq!((details.transferred_at, details.transferred_time), (Some(2), Some(7_000)));
+	});
+}
+
+#[test]
+fn genesis_claims_have_no_recorded_time() {
+	new_test_ext_with_claims(vec![(vec![0, 1], 1)]).execute_with(|| {
+		let details = Poe::claim_details(BlakeTwo256::hash(&[0, 1])).unwrap();
+		assert_eq!(details.created_time, None);
+	});
+}
+
+#[test]
+fn init_storage_version_stamps_only_new_instances() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(PoeReleases::on_chain_storage_version(), 0);
+		InitStorageVersion::<Test, Instance1>::on_runtime_upgrade();
+		assert_eq!(PoeReleases::on_chain_storage_version(), 3);
+
+		// An instance with a storage version is left for its own migrations.
+		StorageVersion::new(2).put::<Poe>();
+		InitStorageVersion::<Test>::on_runtime_upgrade();
+		assert_eq!(Poe::on_chain_storage_version(), 2);
+	});
+}
+
+#[test]
+fn migrate_to_v3_adds_claim_times() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(2).put::<Poe>();
+		let claim = H256::repeat_byte(1);
+		v2::Proofs::<Test>::insert(
+			&claim,
+			v2::ClaimInfo {
+				owner: 2,
+				creator: 1,
+				created_at: 3,
+				transferred_at: Some(7),
+				algorithm: Some(HashAlgorithm::Sha2_256),
+				expires_at: Some(20),
+				frozen: true,
+				deposit: 40,
+			},
+		);
+
+		MigrateToV3::<Test>::on_runtime_upgrade();
+
+		assert_eq!(Poe::on_chain_storage_version(), 3);
+		assert_eq!(
+			Proofs::<Test>::get(&claim),
+			Some(ClaimInfo {
+				owner: 2,
+				creator: 1,
+				created_at: 3,
+				created_time: None,
+				transferred_at: Some(7),
+				transferred_time: None,
+				algorithm: Some(HashAlgorithm::Sha2_256),
+				expires_at: Some(20),
+				frozen: true,
+				deposit: 40,
+			})
+		);
+	});
+}
+
+// Metadata of 28 bytes in all, taking a deposit of 37 as its title costs two per byte.
+fn metadata() -> ClaimMetadata {
+	ClaimMetadata {
+		mime_type: b"text/plain".to_vec(),
+		title: b"Deed".to_vec(),
+		uri: b"ipfs://x".to_vec(),
+		attributes: vec![(b"lang".to_vec(), b"en".to_vec())],
+	}
+}
+
+#[test]
+fn create_claim_with_metadata_reserves_deposit() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+
+		assert_ok!(Poe::create_claim(
+			RuntimeOrigin::signed(1),
+			claim.clone(),
+			None,
+			Some(metadata())
+		));
+		assert_eq!(Balances::reserved_balance(&1), 79);
+		assert_eq!(
+			Poe::claim_details(BlakeTwo256::hash(&claim)).and_then(|details| details.metadata),
+			Some(metadata())
+		);
+	});
+}
+
+#[test]
+fn set_claim_metadata_adjusts_deposit() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		assert_ok!(Poe::create_hashed_claim(RuntimeOrigin::signed(1), claim, None));
+
+		assert_ok!(Poe::set_claim_metadata(RuntimeOrigin::signed(1), claim, metadata()));
+		assert_eq!(Balances::reserved_balance(&1), 79);
+
+		let title = ClaimMetadata { title: b"A".to_vec(), ..Default::default() };
+		assert_ok!(Poe::set_claim_metadata(RuntimeOrigin::signed(1), claim, title.clone()));
+		assert_eq!(Balances::reserved_balance(&1), 49);
+		assert_eq!(ClaimMetadataOf::<Test>::get(&claim).map(Into::into), Some(title));
+
+		assert_ok!(Poe::clear_claim_metadata(RuntimeOrigin::signed(1), claim));
+		assert_eq!(Balances::reserved_balance(&1), 42);
+		assert_noop!(
+			Poe::clear_claim_metadata(RuntimeOrigin::signed(1), claim),
+			Error::<Test>::NoMetadata
+		);
+	});
+}
+
+#[test]
+fn set_claim_metadata_failed_with_parts_too_long() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		assert_ok!(Poe::create_hashed_claim(RuntimeOrigin::signed(1), claim, None));
+
+		let cases = [
+			(
+				ClaimMetadata { mime_type: vec![0; 17], ..metadata() },
+				Error::<Test>::MimeTypeTooLong,
+			),
+			(ClaimMetadata { title: vec![0; 17], ..metadata() }, Error::<Test>::TitleTooLong),
+			(ClaimMetadata { uri: vec![0; 33], ..metadata() }, Error::<Test>::UriTooLong),
+			(
+				ClaimMetadata { attributes: vec![(vec![0], vec![0]); 3], ..metadata() },
+				Error::<Test>::TooManyAttributes,
+			),
+			(
+				ClaimMetadata { attributes: vec![(vec![0; 9], vec![0])], ..metadata() },
+				Error::<Test>::AttributeKeyTooLong,
+			),
+			(
+				ClaimMetadata { attributes: vec![(vec![0], vec![0; 17])], ..metadata() },
+				Error::<Test>::AttributeValueTooLong,
+			),
+		];
+		for (metadata, error) in cases {
+			assert_noop!(Poe::set_claim_metadata(RuntimeOrigin::signed(1), claim, metadata), error);
+		}
+	});
+}
+
+#[test]
+fn set_claim_metadata_failed_when_not_owner() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		assert_ok!(Poe::create_hashed_claim(RuntimeOrigin::signed(1), claim, None));
+
+		assert_noop!(
+			Poe::set_claim_metadata(RuntimeOrigin::signed(2), claim, metadata()),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_noop!(
+			Poe::clear_claim_metadata(RuntimeOrigin::signed(2), claim),
+			Error::<Test>::NotClaimOwner
+		);
+	});
+}
+
+#[test]
+fn metadata_deposit_follows_claim() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		let digest = BlakeTwo256::hash(&claim);
+		assert_ok!(Poe::create_claim(RuntimeOrigin::signed(1), claim, None, Some(metadata())));
+
+		assert_ok!(Poe::transfer_claim(RuntimeOrigin::signed(1), digest, 2));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(Balances::reserved_balance(&2), 79);
+		assert_ok!(Poe::do_try_state());
+
+		assert_ok!(Poe::revoke_claim(RuntimeOrigin::signed(2), digest, None));
+		assert_eq!(Balances::reserved_balance(&2), 4);
+		assert!(!ClaimMetadataOf::<Test>::contains_key(&digest));
+	});
+}
+
+// Commit `who` to `claim` with a fixed salt.
+fn commit(who: u64, claim: &[u8]) -> H256 {
+	let commitment = Poe::commitment_of(&BlakeTwo256::hash(claim), &[7; 32], &who);
+	assert_ok!(Poe::commit_claim(RuntimeOrigin::signed(who), commitment));
+	commitment
+}
+
+#[test]
+fn commit_and_reveal_claim_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = vec![0, 1];
+		let commitment = commit(1, &claim);
+		assert_eq!(Balances::reserved_balance(&1), 42);
+
+		// `MinRevealDelay` is two blocks.
+		System::set_block_number(2);
+		assert_noop!(
+			Poe::reveal_claim(RuntimeOrigin::signed(1), claim.clone(), [7; 32]),
+			Error::<Test>::RevealTooEarly
+		);
+
+		System::set_block_number(3);
+		assert_ok!(Poe::reveal_claim(RuntimeOrigin::signed(1), claim.clone(), [7; 32]));
+		assert_eq!(owner_and_creation(BlakeTwo256::hash(&claim)), Some((1, 3)));
+		assert!(!Commitments::<Test>::contains_key(&commitment));
+		assert_eq!(Balances::reserved_balance(&1), 42);
+		assert_ok!(Poe::do_try_state());
+	});
+}
+
+#[test]
+fn reveal_claim_failed_with_wrong_salt_or_account() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = vec![0, 1];
+		commit(1, &claim);
+
+		System::set_block_number(3);
+		assert_noop!(
+			Poe::reveal_claim(RuntimeOrigin::signed(1), claim.clone(), [8; 32]),
+			Error::<Test>::NoCommitment
+		);
+		assert_noop!(
+			Poe::reveal_claim(RuntimeOrigin::signed(2), claim, [7; 32]),
+			Error::<Test>::NoCommitment
+		);
+	});
+}
+
+#[test]
+fn reveal_claim_displaces_later_claim() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = vec![0, 1];
+		commit(1, &claim);
+
+		// Account 2 copies the claim from the reveal before it is included.
+		System::set_block_number(3);
+		assert_ok!(Poe::create_claim(RuntimeOrigin::signed(2), claim.clone(), None, None));
+
+		assert_ok!(Poe::reveal_claim(RuntimeOrigin::signed(1), claim.clone(), [7; 32]));
+		assert_eq!(owner_and_creation(BlakeTwo256::hash(&claim)), Some((1, 3)));
+		assert_eq!(Poe::claim_count(2), 0);
+		assert_eq!(Balances::reserved_balance(&2), 0);
+		assert_ok!(Poe::do_try_state());
+	});
+}
+
+#[test]
+fn reveal_claim_failed_when_claimed_before_commitment() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = vec![0, 1];
+		assert_ok!(Poe::create_claim(RuntimeOrigin::signed(2), claim.clone(), None, None));
+		commit(1, &claim);
+
+		System::set_block_number(3);
+		assert_noop!(
+			Poe::reveal_claim(RuntimeOrigin::signed(1), claim, [7; 32]),
+			Error::<Test>::ProofAlreadyExists
+		);
+	});
+}
+
+#[test]
+fn stale_commitment_can_be_removed_by_anyone() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = vec![0, 1];
+		let commitment = commit(1, &claim);
+
+		// `CommitmentLifetime` is ten blocks.
+		System::set_block_number(11);
+		assert_noop!(
+			Poe::remove_commitment(RuntimeOrigin::signed(2), commitment),
+			Error::<Test>::CommitmentNotExpired
+		);
+
+		System::set_block_number(12);
+		assert_noop!(
+			Poe::reveal_claim(RuntimeOrigin::signed(1), claim, [7; 32]),
+			Error::<Test>::CommitmentExpired
+		);
+		assert_ok!(Poe::remove_commitment(RuntimeOrigin::signed(2), commitment));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert!(!Commitments::<Test>::contains_key(&commitment));
+	});
+}
+
+// Register a claim of account 1, shared with accounts 2 and 3, of which `threshold` must approve
+// proposals.
+fn co_owned_claim(threshold: u32) -> H256 {
+	let claim = H256::repeat_byte(1);
+	assert_ok!(Poe::create_hashed_claim(RuntimeOrigin::signed(1), claim, None));
+	assert_ok!(Poe::set_co_owners(RuntimeOrigin::signed(1), claim, vec![1, 2, 3], threshold));
+	claim
+}
+
+#[test]
+fn set_co_owners_failed_with_invalid_owners() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		assert_ok!(Poe::create_hashed_claim(RuntimeOrigin::signed(1), claim, None));
+
+		let set = |owners: Vec<u64>, threshold| {
+			Poe::set_co_owners(RuntimeOrigin::signed(1), claim, owners, threshold)
+		};
+		assert_noop!(set(vec![1, 2, 3, 4], 2), Error::<Test>::TooManyCoOwners);
+		assert_noop!(set(vec![2, 3], 2), Error::<Test>::InvalidCoOwners);
+		assert_noop!(set(vec![1, 2, 2], 2), Error::<Test>::InvalidCoOwners);
+		assert_noop!(set(vec![1, 2], 0), Error::<Test>::InvalidThreshold);
+		assert_noop!(set(vec![1, 2], 3), Error::<Test>::InvalidThreshold);
+		assert_noop!(
+			Poe::set_co_owners(RuntimeOrigin::signed(2), claim, vec![1, 2], 2),
+			Error::<Test>::NotClaimOwner
+		);
+	});
+}
+
+#[test]
+fn co_owned_claim_requires_proposals() {
+	new_test_ext().execute_with(|| {
+		let claim = co_owned_claim(2);
+		assert_eq!(
+			Poe::claim_details(claim).map(|details| (details.co_owners, details.threshold)),
+			Some((vec![1, 2, 3], 2))
+		);
+
+		assert_noop!(
+			Poe::revoke_claim(RuntimeOrigin::signed(1), claim, None),
+			Error::<Test>::ClaimCoOwned
+		);
+		assert_noop!(
+			Poe::transfer_claim(RuntimeOrigin::signed(1), claim, 2),
+			Error::<Test>::ClaimCoOwned
+		);
+		assert_noop!(
+			Poe::offer_claim(RuntimeOrigin::signed(1), claim, 2),
+			Error::<Test>::ClaimCoOwned
+		);
+		assert_noop!(
+			Poe::propose_revoke(RuntimeOrigin::signed(4), claim),
+			Error::<Test>::NotClaimOwner
+		);
+
+		// Neither co-owner can change the metadata on their own.
+		for who in [1, 2] {
+			assert_noop!(
+				Poe::set_claim_metadata(RuntimeOrigin::signed(who), claim, metadata()),
+				Error::<Test>::ClaimCoOwned
+			);
+			assert_noop!(
+				Poe::clear_claim_metadata(RuntimeOrigin::signed(who), claim),
+				Error::<Test>::ClaimCoOwned
+			);
+		}
+	});
+}
+
+#[test]
+fn propose_transfer_executes_once_approved() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = co_owned_claim(2);
+
+		assert_ok!(Poe::propose_transfer(RuntimeOrigin::signed(2), claim, 3));
+		assert_eq!(owner_and_creation(claim), Some((1, 1)));
+		assert_noop!(
+			Poe::propose_revoke(RuntimeOrigin::signed(3), claim),
+			Error::<Test>::ProposalExists
+		);
+		assert_noop!(
+			Poe::approve_proposal(RuntimeOrigin::signed(2), claim),
+			Error::<Test>::AlreadyApproved
+		);
+
+		assert_ok!(Poe::approve_proposal(RuntimeOrigin::signed(1), claim));
+		System::assert_last_event(Event::<Test>::ProposalExecuted { claim }.into());
+		assert_eq!(owner_and_creation(claim), Some((3, 1)));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(Balances::reserved_balance(&3), 42);
+		assert!(!CoOwners::<Test>::contains_key(&claim));
+		assert_ok!(Poe::do_try_state());
+	});
+}
+
+#[test]
+fn propose_revoke_executes_at_threshold_of_one() {
+	new_test_ext().execute_with(|| {
+		let claim = co_owned_claim(1);
+
+		assert_ok!(Poe::propose_revoke(RuntimeOrigin::signed(2), claim));
+		assert!(!Proofs::<Test>::contains_key(&claim));
+		assert!(!CoOwners::<Test>::contains_key(&claim));
+		assert_eq!(Balances::reserved_balance(&1), 4);
+	});
+}
+
+#[test]
+fn expired_proposal_can_be_replaced() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = co_owned_claim(2);
+		assert_ok!(Poe::propose_revoke(RuntimeOrigin::signed(2), claim));
+		assert_noop!(
+			Poe::cancel_proposal(RuntimeOrigin::signed(3), claim),
+			Error::<Test>::ProposalNotExpired
+		);
+
+		// `ProposalDuration` is ten blocks.
+		System::set_block_number(12);
+		assert_noop!(
+			Poe::approve_proposal(RuntimeOrigin::signed(1), claim),
+			Error::<Test>::ProposalExpired
+		);
+		assert_ok!(Poe::propose_transfer(RuntimeOrigin::signed(3), claim, 2));
+		assert_ok!(Poe::cancel_proposal(RuntimeOrigin::signed(3), claim));
+		assert_noop!(
+			Poe::cancel_proposal(RuntimeOrigin::signed(3), claim),
+			Error::<Test>::NoProposal
+		);
+	});
+}
+
+#[test]
+fn attest_claim_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = H256::repeat_byte(1);
+		assert_noop!(
+			Poe::attest_claim(RuntimeOrigin::signed(2), claim, None),
+			Error::<Test>::ClaimNotExists
+		);
+		assert_ok!(Poe::create_hashed_claim(RuntimeOrigin::signed(1), claim, None));
+
+		assert_ok!(Poe::attest_claim(RuntimeOrigin::signed(2), claim, Some(b"seen".to_vec())));
+		System::assert_last_event(Event::<Test>::ClaimAttested { attester: 2, claim }.into());
+		System::set_block_number(2);
+		assert_ok!(Poe::attest_claim(RuntimeOrigin::signed(3), claim, None));
+		assert_eq!(
+			Poe::claim_attestations(claim),
+			vec![
+				Attestation { attester: 2, attested_at: 1, note: Some(b"seen".to_vec()) },
+				Attestation { attester: 3, attested_at: 2, note: None },
+			]
+		);
+		// Attesting gives no rights over the claim, but takes a deposit.
+		assert_eq!(owner_and_creation(claim), Some((1, 1)));
+		assert_eq!(Balances::reserved_balance(&2), 3);
+		assert_eq!(Balances::reserved_balance(&3), 3);
+		assert_ok!(Poe::do_try_state());
+	});
+}
+
+#[test]
+fn attest_claim_failed_with_invalid_attestation() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		assert_ok!(Poe::create_hashed_claim(RuntimeOrigin::signed(1), claim, None));
+
+		assert_noop!(
+			Poe::attest_claim(RuntimeOrigin::signed(2), claim, Some(vec![0; 9])),
+			Error::<Test>::NoteTooLong
+		);
+		// Account 4 cannot cover the deposit.
+		assert_noop!(
+			Poe::attest_claim(RuntimeOrigin::signed(4), claim, None),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+		assert_ok!(Poe::attest_claim(RuntimeOrigin::signed(2), claim, None));
+		assert_noop!(
+			Poe::attest_claim(RuntimeOrigin::signed(2), claim, None),
+			Error::<Test>::AlreadyAttested
+		);
+		// `MaxAttestations` is two.
+		assert_ok!(Poe::attest_claim(RuntimeOrigin::signed(3), claim, None));
+		assert_noop!(
+			Poe::attest_claim(RuntimeOrigin::signed(4), claim, None),
+			Error::<Test>::TooManyAttestations
+		);
+	});
+}
+
+#[test]
+fn withdraw_attestation_works() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		assert_ok!(Poe::create_hashed_claim(RuntimeOrigin::signed(1), claim, None));
+		assert_ok!(Poe::attest_claim(RuntimeOrigin::signed(2), claim, None));
+		assert_ok!(Poe::attest_claim(RuntimeOrigin::signed(3), claim, None));
+
+		assert_ok!(Poe::withdraw_attestation(RuntimeOrigin::signed(2), claim));
+		System::assert_last_event(
+			Event::<Test>::AttestationWithdrawn { attester: 2, claim }.into(),
+		);
+		assert_eq!(
+			Poe::claim_attestations(claim),
+			vec![Attestation { attester: 3, attested_at: 0, note: None }]
+		);
+		assert_eq!(Balances::reserved_balance(&2), 0);
+		assert_noop!(
+			Poe::withdraw_attestation(RuntimeOrigin::signed(2), claim),
+			Error::<Test>::NotAttested
+		);
+
+		assert_ok!(Poe::withdraw_attestation(RuntimeOrigin::signed(3), claim));
+		assert!(!Attestations::<Test>::contains_key(&claim));
+		assert_eq!(Balances::reserved_balance(&3), 0);
+		assert_ok!(Poe::do_try_state());
+	});
+}
+
+#[test]
+fn remove_attestation_works() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		assert_ok!(Poe::create_hashed_claim(RuntimeOrigin::signed(1), claim, None));
+		assert_ok!(Poe::attest_claim(RuntimeOrigin::signed(2), claim, None));
+		assert_ok!(Poe::attest_claim(RuntimeOrigin::signed(3), claim, None));
+
+		// Only the owner or the force origin may remove the attestations of others.
+		assert_noop!(
+			Poe::remove_attestation(RuntimeOrigin::signed(3), claim, 2),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_noop!(
+			Poe::remove_attestation(RuntimeOrigin::signed(1), claim, 4),
+			Error::<Test>::NotAttested
+		);
+
+		assert_ok!(Poe::remove_attestation(RuntimeOrigin::signed(1), claim, 2));
+		System::assert_last_event(Event::<Test>::AttestationRemoved { attester: 2, claim }.into());
+		assert_eq!(Poe::claim_attestations(claim).len(), 1);
+		assert_eq!(Balances::reserved_balance(&2), 0);
+
+		assert_ok!(Poe::remove_attestation(RuntimeOrigin::root(), claim, 3));
+		assert!(!Attestations::<Test>::contains_key(&claim));
+		assert_eq!(Balances::reserved_balance(&3), 0);
+		assert_ok!(Poe::do_try_state());
+	});
+}
+
+#[test]
+fn revoke_claim_removes_attestations() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		assert_ok!(Poe::create_hashed_claim(RuntimeOrigin::signed(1), claim, None));
+		assert_ok!(Poe::attest_claim(RuntimeOrigin::signed(2), claim, None));
+
+		// Attestations outlive a change of owner, but not the claim itself.
+		assert_ok!(Poe::transfer_claim(RuntimeOrigin::signed(1), claim, 3));
+		assert_eq!(Poe::claim_attestations(claim).len(), 1);
+		assert_ok!(Poe::revoke_claim(RuntimeOrigin::signed(3), claim, None));
+		assert!(!Attestations::<Test>::contains_key(&claim));
+		assert_eq!(Balances::reserved_balance(&2), 0);
+		assert_ok!(Poe::do_try_state());
+	});
+}
+
+#[test]
+fn supersede_claim_links_revisions() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (first, second, third) =
+			(H256::repeat_byte(1), H256::repeat_byte(2), H256::repeat_byte(3));
+		assert_ok!(Poe::create_hashed_claim(
+			RuntimeOrigin::signed(1),
+			first,
+			Some(HashAlgorithm::Sha2_256)
+		));
+
+		assert_ok!(Poe::supersede_claim(RuntimeOrigin::signed(1), first, second));
+		System::assert_last_event(
+			Event::<Test>::ClaimSuperseded { owner: 1, old: first, new: second }.into(),
+		);
+		assert_ok!(Poe::supersede_claim(RuntimeOrigin::signed(1), second, third));
+
+		// The old revisions are kept, each with its own deposit.
+		assert_eq!(Balances::reserved_balance(&1), 3 * 42);
+		assert_eq!(Poe::claim_details(first).map(|details| details.superseded), Some(true));
+		assert_eq!(
+			Poe::claim_details(third).map(|details| (details.superseded, details.algorithm)),
+			Some((false, Some(HashAlgorithm::Sha2_256)))
+		);
+		for claim in [first, second, third] {
+			assert_eq!(Poe::revision_chain(claim), vec![first, second, third]);
+		}
+		assert!(Poe::revision_chain(H256::repeat_byte(4)).is_empty());
+		assert_ok!(Poe::do_try_state());
+	});
+}
+
+#[test]
+fn supersede_claim_failed_with_invalid_claims() {
+	new_test_ext().execute_with(|| {
+		let (old, new) = (H256::repeat_byte(1), H256::repeat_byte(2));
+		assert_noop!(
+			Poe::supersede_claim(RuntimeOrigin::signed(1), old, new),
+			Error::<Test>::ClaimNotExists
+		);
+		assert_ok!(Poe::create_hashed_claim(RuntimeOrigin::signed(1), old, None));
+		assert_noop!(
+			Poe::supersede_claim(RuntimeOrigin::signed(2), old, new),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_noop!(
+			Poe::supersede_claim(RuntimeOrigin::signed(1), old, old),
+			Error::<Test>::ProofAlreadyExists
+		);
+
+		assert_ok!(Poe::supersede_claim(RuntimeOrigin::signed(1), old, new));
+		assert_noop!(
+			Poe::supersede_claim(RuntimeOrigin::signed(1), old, H256::repeat_byte(3)),
+			Error::<Test>::AlreadySuperseded
+		);
+	});
+}
+
+#[test]
+fn revoke_claim_closes_revision_gap() {
+	new_test_ext().execute_with(|| {
+		let (first, second, third) =
+			(H256::repeat_byte(1), H256::repeat_byte(2), H256::repeat_byte(3));
+		assert_ok!(Poe::create_hashed_claim(RuntimeOrigin::signed(1), first, None));
+		assert_ok!(Poe::supersede_claim(RuntimeOrigin::signed(1), first, second));
+		assert_ok!(Poe::supersede_claim(RuntimeOrigin::signed(1), second, third));
+
+		assert_ok!(Poe::revoke_claim(RuntimeOrigin::signed(1), second, None));
+		assert_eq!(Poe::revision_chain(third), vec![first, third]);
+		assert!(!ClaimRevisions::<Test>::contains_key(&second));
+
+		// Removing the latest revision leaves the one before it free to be superseded again.
+		assert_ok!(Poe::revoke_claim(RuntimeOrigin::signed(1), third, None));
+		assert_eq!(Poe::revision_chain(first), vec![first]);
+		assert_eq!(Poe::claim_details(first).map(|details| details.superseded), Some(false));
+		assert!(!ClaimRevisions::<Test>::contains_key(&first));
+		assert_ok!(Poe::do_try_state());
+	});
+}
+
+#[test]
+fn revoke_claim_leaves_tombstone() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = H256::repeat_byte(1);
+		assert_ok!(Poe::create_hashed_claim(RuntimeOrigin::signed(1), claim, None));
+		assert_eq!(Poe::revocation_of(claim), None);
+
+		assert_noop!(
+			Poe::revoke_claim(RuntimeOrigin::signed(1), claim, Some(vec![0; 9])),
+			Error::<Test>::ReasonTooLong
+		);
+		System::set_block_number(2);
+		assert_ok!(Poe::revoke_claim(RuntimeOrigin::signed(1), claim, Some(b"amended".to_vec())));
+		assert_eq!(Poe::claim_details(claim), None);
+		assert_eq!(
+			Poe::revocation_of(claim),
+			Some(Revocation {
+				owner: 1,
+				revoker: Some(1),
+				revoked_at: 2,
+				reason: Some(b"amended".to_vec()),
+			})
+		);
+		assert_ok!(Poe::do_try_state());
+	});
+}
+
+#[test]
+fn revoked_claim_is_reserved_for_its_owner() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		assert_ok!(Poe::create_hashed_claim(RuntimeOrigin::signed(1), claim, None));
+		assert_ok!(Poe::revoke_claim(RuntimeOrigin::signed(1), claim, None));
+
+		assert_noop!(
+			Poe::create_hashed_claim(RuntimeOrigin::signed(2), claim, None),
+			Error::<Test>::ClaimWasRevoked
+		);
+		assert_ok!(Poe::create_hashed_claim(RuntimeOrigin::signed(1), claim, None));
+		assert_eq!(Poe::revocation_of(claim), None);
+		// The tombstone deposit is returned along with the tombstone.
+		assert_eq!(Balances::reserved_balance(&1), 42);
+		assert_ok!(Poe::do_try_state());
+	});
+}
+
+#[test]
+fn force_revoked_claim_is_not_reserved_for_its_owner() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		assert_ok!(Poe::create_hashed_claim(RuntimeOrigin::signed(1), claim, None));
+		assert_ok!(Poe::force_revoke(RuntimeOrigin::root(), claim, None));
+		// A tombstone left by the force origin takes no deposit from the owner.
+		assert_eq!(Balances::reserved_balance(&1), 0);
+
+		assert_noop!(
+			Poe::create_hashed_claim(RuntimeOrigin::signed(1), claim, None),
+			Error::<Test>::ClaimWasRevoked
+		);
+		assert_noop!(
+			Poe::clear_tombstone(RuntimeOrigin::signed(1), claim),
+			Error::<Test>::ClaimWasRevoked
+		);
+		assert_ok!(Poe::do_try_state());
+	});
+}
+
+#[test]
+fn clear_tombstone_works() {
+	new_test_ext().execute_with(|| {
+		let (claim, forced) = (H256::repeat_byte(1), H256::repeat_byte(2));
+		assert_ok!(Poe::create_hashed_claim(RuntimeOrigin::signed(1), claim, None));
+		assert_ok!(Poe::create_hashed_claim(RuntimeOrigin::signed(1), forced, None));
+		assert_ok!(Poe::revoke_claim(RuntimeOrigin::signed(1), claim, None));
+		assert_ok!(Poe::force_revoke(RuntimeOrigin::root(), forced, None));
+		assert_noop!(
+			Poe::clear_tombstone(RuntimeOrigin::signed(1), H256::repeat_byte(3)),
+			Error::<Test>::NoTombstone
+		);
+
+		// Only the last owner or the force origin may clear a tombstone.
+		assert_noop!(
+			Poe::clear_tombstone(RuntimeOrigin::signed(2), claim),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_ok!(Poe::clear_tombstone(RuntimeOrigin::signed(1), claim));
+		System::assert_last_event(Event::<Test>::TombstoneCleared { owner: 1, claim }.into());
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_ok!(Poe::create_hashed_claim(RuntimeOrigin::signed(2), claim, None));
+
+		assert_ok!(Poe::clear_tombstone(RuntimeOrigin::root(), forced));
+		assert_eq!(Poe::revocation_of(forced), None);
+		assert_ok!(Poe::create_hashed_claim(RuntimeOrigin::signed(1), forced, None));
+		assert_ok!(Poe::do_try_state());
+	});
+}
+
+#[test]
+fn revoked_claim_can_be_registered_by_others_when_allowed() {
+	new_test_ext().execute_with(|| {
+		AllowReregistration::set(true);
+		let claim = H256::repeat_byte(1);
+		assert_ok!(Poe::create_hashed_claim(RuntimeOrigin::signed(1), claim, None));
+		assert_ok!(Poe::revoke_claim(RuntimeOrigin::signed(1), claim, None));
+
+		assert_ok!(Poe::create_hashed_claim(RuntimeOrigin::signed(2), claim, None));
+		assert_eq!(owner_and_creation(claim), Some((2, 0)));
+		assert!(!Tombstones::<Test>::contains_key(&claim));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+	});
+}
+
+#[test]
+fn force_and_proposed_revocations_record_revoker() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		assert_ok!(Poe::create_hashed_claim(RuntimeOrigin::signed(1), claim, None));
+		assert_ok!(Poe::force_revoke(RuntimeOrigin::root(), claim, Some(b"court".to_vec())));
+		assert_eq!(
+			Poe::revocation_of(claim).map(|revocation| (revocation.revoker, revocation.reason)),
+			Some((None, Some(b"court".to_vec())))
+		);
+
+		let claim = co_owned_claim(2);
+		assert_ok!(Poe::propose_revoke(RuntimeOrigin::signed(2), claim));
+		assert_ok!(Poe::approve_proposal(RuntimeOrigin::signed(3), claim));
+		assert_eq!(
+			Poe::revocation_of(claim).map(|revocation| (revocation.owner, revocation.revoker)),
+			Some((1, Some(3)))
+		);
+	});
+}
+
+#[test]
+fn expired_claim_leaves_no_tombstone() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(Poe::create_claim(RuntimeOrigin::signed(1), claim.clone(), Some(5), None));
+
+		Poe::on_initialize(5);
+		let claim = BlakeTwo256::hash(&claim);
+		assert!(!Proofs::<Test>::contains_key(&claim));
+		assert_eq!(Poe::revocation_of(claim), None);
+	});
+}
+
+#[test]
+fn events_are_indexed_by_claim_and_account() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (claim, other) = (H256::repeat_byte(1), H256::repeat_byte(2));
+		assert_ok!(Poe::create_hashed_claim(RuntimeOrigin::signed(1), claim, None));
+		assert_ok!(Poe::create_hashed_claim(RuntimeOrigin::signed(1), other, None));
+		assert_ok!(Poe::transfer_claim(RuntimeOrigin::signed(1), claim, 2));
+
+		let events_of = |topic: H256| -> Vec<RuntimeEvent> {
+			let events = System::events();
+			System::event_topics(&topic)
+				.into_iter()
+				.map(|(_, index)| events[index as usize].event.clone())
+				.collect()
+		};
+		let created = |claim| Event::<Test>::ClaimCreated { owner: 1, claim, created_time: None };
+		let transferred =
+			Event::<Test>::ClaimTransferred { from: 1, to: 2, claim, transferred_time: None };
+		assert_eq!(events_of(claim), vec![created(claim).into(), transferred.clone().into()]);
+		assert_eq!(
+			events_of(BlakeTwo256::hash_of(&1u64)),
+			vec![created(claim).into(), created(other).into(), transferred.clone().into()]
+		);
+		assert_eq!(events_of(BlakeTwo256::hash_of(&2u64)), vec![transferred.into()]);
+	});
+}
+
+#[test]
+fn instances_keep_independent_registries() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = vec![0, 1];
+		let digest = BlakeTwo256::hash(&claim);
+		assert_ok!(Poe::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
+		assert_ok!(PoeReleases::create_claim(RuntimeOrigin::signed(2), claim.clone(), None, None));
+
+		assert_eq!(Proofs::<Test>::get(&digest).map(|info| info.owner), Some(1));
+		assert_eq!(Proofs::<Test, Instance1>::get(&digest).map(|info| info.owner), Some(2));
+		assert_eq!(Balances::reserved_balance_named(&PoeReserveId::get(), &1), 42);
+		assert_eq!(Balances::reserved_balance_named(&ReleaseReserveId::get(), &2), 84);
+		assert_eq!(Balances::reserved_balance_named(&ReleaseReserveId::get(), &1), 0);
+
+		assert_ok!(Poe::revoke_claim(RuntimeOrigin::signed(1), digest, None));
+		assert!(!Proofs::<Test>::contains_key(&digest));
+		assert!(Proofs::<Test, Instance1>::contains_key(&digest));
+		assert_eq!(Balances::reserved_balance_named(&PoeReserveId::get(), &1), 4);
+		assert_eq!(Balances::reserved_balance_named(&ReleaseReserveId::get(), &2), 84);
+
+		System::assert_has_event(
+			Event::<Test, Instance1>::ClaimCreated { owner: 2, claim: digest, created_time: None }
+				.into(),
+		);
+		assert_ok!(Poe::do_try_state());
+		assert_ok!(PoeReleases::do_try_state());
+	});
+}
+
+#[test]
+fn instances_apply_their_own_limits_and_origins() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0; 17];
+		assert_noop!(
+			PoeReleases::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None),
+			Error::<Test, Instance1>::ClaimTooLong
+		);
+		assert_ok!(Poe::create_claim(RuntimeOrigin::signed(1), claim, None, None));
+
+		let claim = vec![0, 1];
+		assert_ok!(Poe::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
+		assert_ok!(PoeReleases::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
+		let digest = BlakeTwo256::hash(&claim);
+
+		assert_noop!(Poe::force_revoke(RuntimeOrigin::signed(3), digest, None), BadOrigin);
+		assert_noop!(PoeReleases::force_revoke(RuntimeOrigin::root(), digest, None), BadOrigin);
+		assert_ok!(PoeReleases::force_revoke(RuntimeOrigin::signed(3), digest, None));
+		assert!(!Proofs::<Test, Instance1>::contains_key(&digest));
+		assert!(Proofs::<Test>::contains_key(&digest));
+	});
+}
+
+#[test]
+fn other_pallets_manage_claims_through_the_trait() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(3);
+		let claim = H256::repeat_byte(1);
+		assert!(!<Poe as ProofOfExistence<u64, u64>>::exists(&claim));
+		assert_eq!(<Poe as ProofOfExistence<u64, u64>>::owner_of(&claim), None);
+
+		assert_ok!(<Poe as ProofOfExistence<u64, u64>>::do_create(&1, claim));
+		assert!(<Poe as ProofOfExistence<u64, u64>>::exists(&claim));
+		assert_eq!(<Poe as ProofOfExistence<u64, u64>>::owner_of(&claim), Some(1));
+		assert_eq!(<Poe as ProofOfExistence<u64, u64>>::created_at(&claim), Some(3));
+		assert!(!<PoeReleases as ProofOfExistence<u64, u64>>::exists(&claim));
+		assert_noop!(
+			<Poe as ProofOfExistence<u64, u64>>::do_create(&2, claim),
+			Error::<Test>::ProofAlreadyExists
+		);
+
+		assert_noop!(
+			<Poe as ProofOfExistence<u64, u64>>::do_transfer(&2, &claim, &3),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_ok!(<Poe as ProofOfExistence<u64, u64>>::do_transfer(&1, &claim, &2));
+		assert_eq!(<Poe as ProofOfExistence<u64, u64>>::owner_of(&claim), Some(2));
+
+		assert_ok!(Poe::freeze_claim(RuntimeOrigin::root(), claim));
+		assert_noop!(
+			<Poe as ProofOfExistence<u64, u64>>::do_revoke(&2, &claim),
+			Error::<Test>::ClaimFrozen
+		);
+		assert_ok!(Poe::unfreeze_claim(RuntimeOrigin::root(), claim));
+		assert_noop!(
+			<Poe as ProofOfExistence<u64, u64>>::do_revoke(&1, &claim),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_ok!(<Poe as ProofOfExistence<u64, u64>>::do_revoke(&2, &claim));
+		assert!(!<Poe as ProofOfExistence<u64, u64>>::exists(&claim));
+		assert_eq!(Poe::revocation_of(claim).map(|revocation| revocation.revoker), Some(Some(2)));
+	});
+}
+
+#[test]
+fn failed_trait_calls_leave_storage_untouched() {
+	new_test_ext().execute_with(|| {
+		AllowReregistration::set(true);
+		let claim = H256::repeat_byte(1);
+		assert_ok!(Poe::create_hashed_claim(RuntimeOrigin::signed(1), claim, None));
+		assert_ok!(Poe::revoke_claim(RuntimeOrigin::signed(1), claim, None));
+
+		// Account 4 cannot cover the deposit, which is only found out after the claim was
+		// indexed and its tombstone taken. Unlike a dispatchable, nothing rolls that back for the
+		// caller.
+		assert_noop!(
+			<Poe as ProofOfExistence<u64, u64>>::do_create(&4, claim),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+		assert!(!ClaimsByOwner::<Test>::contains_key(4, claim));
+		assert_eq!(Poe::claim_count(4), 0);
+		assert!(Tombstones::<Test>::contains_key(&claim));
+		assert_ok!(Poe::do_try_state());
+	});
+}
+
+#[test]
+fn root_owns_claims_through_its_account() {
+	new_test_ext().execute_with(|| {
+		let root_owner = RootClaimOwner::get();
+		Balances::make_free_balance_be(&root_owner, 1_000);
+		let claim = vec![0, 1];
+		let digest = BlakeTwo256::hash(&claim);
+
+		assert_noop!(
+			Poe::create_claim(RuntimeOrigin::none(), claim.clone(), None, None),
+			BadOrigin
+		);
+		assert_ok!(Poe::create_claim(RuntimeOrigin::root(), claim, None, None));
+		assert_eq!(owner_and_creation(digest).map(|(owner, _)| owner), Some(root_owner));
+		assert_eq!(Balances::reserved_balance_named(&PoeReserveId::get(), &root_owner), 42);
+
+		// Only root, acting as the owning account, can transfer or revoke the claim.
+		assert_noop!(
+			Poe::revoke_claim(RuntimeOrigin::signed(1), digest, None),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_noop!(
+			Poe::transfer_claim(RuntimeOrigin::signed(1), digest, 2),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_ok!(Poe::transfer_claim(RuntimeOrigin::root(), digest, 1));
+		assert_eq!(owner_and_creation(digest).map(|(owner, _)| owner), Some(1));
+		assert_noop!(
+			Poe::revoke_claim(RuntimeOrigin::root(), digest, None),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_ok!(Poe::revoke_claim(RuntimeOrigin::signed(1), digest, None));
+
+		// The release registry only lets signed accounts own claims.
+		assert_noop!(
+			PoeReleases::create_hashed_claim(RuntimeOrigin::root(), digest, None),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn listed_claims_are_sold_with_a_royalty_to_their_creator() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = H256::repeat_byte(1);
+		assert_ok!(Poe::create_hashed_claim(RuntimeOrigin::signed(1), claim, None));
+
+		assert_noop!(
+			Poe::list_claim(RuntimeOrigin::signed(2), claim, 100),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_noop!(
+			Poe::buy_claim(RuntimeOrigin::signed(2), claim, 100),
+			Error::<Test>::NotListed
+		);
+		assert_ok!(Poe::list_claim(RuntimeOrigin::signed(1), claim, 100));
+		assert_eq!(Listings::<Test>::get(&claim), Some(100));
+		assert_noop!(
+			Poe::buy_claim(RuntimeOrigin::signed(1), claim, 100),
+			Error::<Test>::AlreadyClaimOwner
+		);
+
+		// The creator sells the claim, so no royalty is due.
+		assert_ok!(Poe::buy_claim(RuntimeOrigin::signed(2), claim, 100));
+		assert_eq!(owner_and_creation(claim).map(|(owner, _)| owner), Some(2));
+		assert!(!Listings::<Test>::contains_key(&claim));
+		assert_eq!((Balances::free_balance(&1), Balances::reserved_balance(&1)), (1_058, 0));
+		assert_eq!((Balances::free_balance(&2), Balances::reserved_balance(&2)), (900, 42));
+
+		// A resale pays the creator `RoyaltyRate`, ten percent, of the price.
+		assert_ok!(Poe::list_claim(RuntimeOrigin::signed(2), claim, 200));
+		assert_noop!(
+			Poe::buy_claim(RuntimeOrigin::signed(3), claim, 150),
+			Error::<Test>::PriceTooHigh
+		);
+		assert_ok!(Poe::buy_claim(RuntimeOrigin::signed(3), claim, 250));
+		assert_eq!(owner_and_creation(claim).map(|(owner, _)| owner), Some(3));
+		assert_eq!(Balances::free_balance(&1), 1_078);
+		assert_eq!((Balances::free_balance(&2), Balances::reserved_balance(&2)), (1_080, 0));
+		assert_eq!((Balances::free_balance(&3), Balances::reserved_balance(&3)), (800, 42));
+		System::assert_has_event(
+			Event::<Test>::ClaimSold { seller: 2, buyer: 3, claim, price: 200, royalty: 20 }.into(),
+		);
+		assert_ok!(Poe::do_try_state());
+	});
+}
+
+#[test]
+fn royalty_too_small_for_a_reaped_creator_goes_to_the_seller() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		assert_ok!(Poe::create_hashed_claim(RuntimeOrigin::signed(1), claim, None));
+		assert_ok!(Poe::list_claim(RuntimeOrigin::signed(1), claim, 100));
+		assert_ok!(Poe::buy_claim(RuntimeOrigin::signed(2), claim, 100));
+
+		// The creator is reaped, and a royalty of 20 cannot bring its account back.
+		Balances::make_free_balance_be(&1, 0);
+		assert_eq!(Balances::total_balance(&1), 0);
+		ExistentialDeposit::set(50);
+		assert_ok!(Poe::list_claim(RuntimeOrigin::signed(2), claim, 200));
+		assert_ok!(Poe::buy_claim(RuntimeOrigin::signed(3), claim, 200));
+		assert_eq!(owner_and_creation(claim).map(|(owner, _)| owner), Some(3));
+		assert_eq!(Balances::total_balance(&1), 0);
+		assert_eq!(Balances::free_balance(&2), 1_100);
+		System::assert_has_event(
+			Event::<Test>::ClaimSold { seller: 2, buyer: 3, claim, price: 200, royalty: 0 }.into(),
+		);
+	});
+}
+
+#[test]
+fn listings_end_when_claims_change_hands() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		assert_ok!(Poe::create_hashed_claim(RuntimeOrigin::signed(1), claim, None));
+
+		assert_ok!(Poe::list_claim(RuntimeOrigin::signed(1), claim, 100));
+		assert_noop!(
+			Poe::delist_claim(RuntimeOrigin::signed(2), claim),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_ok!(Poe::delist_claim(RuntimeOrigin::signed(1), claim));
+		assert_noop!(Poe::delist_claim(RuntimeOrigin::signed(1), claim), Error::<Test>::NotListed);
+
+		assert_ok!(Poe::list_claim(RuntimeOrigin::signed(1), claim, 100));
+		assert_ok!(Poe::transfer_claim(RuntimeOrigin::signed(1), claim, 2));
+		assert_noop!(
+			Poe::buy_claim(RuntimeOrigin::signed(3), claim, 100),
+			Error::<Test>::NotListed
+		);
+
+		assert_ok!(Poe::list_claim(RuntimeOrigin::signed(2), claim, 100));
+		assert_ok!(Poe::freeze_claim(RuntimeOrigin::root(), claim));
+		assert_noop!(
+			Poe::buy_claim(RuntimeOrigin::signed(3), claim, 100),
+			Error::<Test>::ClaimFrozen
+		);
+		assert_ok!(Poe::unfreeze_claim(RuntimeOrigin::root(), claim));
+
+		assert_ok!(Poe::set_co_owners(RuntimeOrigin::signed(2), claim, vec![2, 3], 2));
+		assert!(!Listings::<Test>::contains_key(&claim));
+		assert_noop!(
+			Poe::list_claim(RuntimeOrigin::signed(2), claim, 100),
+			Error::<Test>::ClaimCoOwned
+		);
+		assert_ok!(Poe::do_try_state());
+	});
+}
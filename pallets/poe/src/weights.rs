@@ -0,0 +1,457 @@
+
+//! Placeholder weights for pallet_poe
+//!
+//! These weights were not measured. They are rough figures that keep fees in proportion until
+//! the file is regenerated with the command below on the reference hardware, which overwrites
+//! it with the standard template. Costs that grow with the number of attestations on a claim
+//! are taken as a component, so callers charge for the configured `MaxAttestations`.
+
+// Command to regenerate this file:
+// ./target/release/node-template
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_poe
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --output=pallets/poe/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_poe.
+pub trait WeightInfo {
+	fn create_claim(l: u32, ) -> Weight;
+	fn revoke_claim(a: u32, ) -> Weight;
+	fn transfer_claim() -> Weight;
+	fn create_hashed_claim() -> Weight;
+	fn offer_claim() -> Weight;
+	fn accept_claim() -> Weight;
+	fn cancel_offer() -> Weight;
+	fn create_batch_root() -> Weight;
+	fn renew_claim() -> Weight;
+	fn expire_claims(n: u32, ) -> Weight;
+	fn force_revoke(a: u32, ) -> Weight;
+	fn force_transfer() -> Weight;
+	fn freeze_claim() -> Weight;
+	fn unfreeze_claim() -> Weight;
+	fn set_claim_metadata() -> Weight;
+	fn clear_claim_metadata() -> Weight;
+	fn commit_claim() -> Weight;
+	fn reveal_claim(l: u32, a: u32, ) -> Weight;
+	fn remove_commitment() -> Weight;
+	fn set_co_owners(n: u32, ) -> Weight;
+	fn propose_revoke(a: u32, ) -> Weight;
+	fn propose_transfer() -> Weight;
+	fn approve_revoke_proposal(a: u32, ) -> Weight;
+	fn approve_transfer_proposal() -> Weight;
+	fn cancel_proposal() -> Weight;
+	fn attest_claim() -> Weight;
+	fn withdraw_attestation() -> Weight;
+	fn supersede_claim() -> Weight;
+	fn list_claim() -> Weight;
+	fn delist_claim() -> Weight;
+	fn buy_claim() -> Weight;
+	fn remove_attestation() -> Weight;
+	fn clear_tombstone() -> Weight;
+}
+
+/// Weights for pallet_poe, to be measured for the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn create_claim(l: u32, ) -> Weight {
+		Weight::from_parts(48_884_102, 25963)
+			.saturating_add(Weight::from_parts(1_874, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	fn revoke_claim(a: u32, ) -> Weight {
+		Weight::from_parts(53_000_000, 32448)
+			.saturating_add(Weight::from_parts(8_000_000, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes(15_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(0, 2239).saturating_mul(a.into()))
+	}
+	fn transfer_claim() -> Weight {
+		Weight::from_parts(68_000_000, 38274)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(14_u64))
+	}
+	fn create_hashed_claim() -> Weight {
+		Weight::from_parts(45_000_000, 24474)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	fn offer_claim() -> Weight {
+		Weight::from_parts(24_000_000, 9140)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn accept_claim() -> Weight {
+		Weight::from_parts(72_000_000, 38793)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(14_u64))
+	}
+	fn cancel_offer() -> Weight {
+		Weight::from_parts(22_000_000, 7152)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn create_batch_root() -> Weight {
+		Weight::from_parts(46_000_000, 24474)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	fn renew_claim() -> Weight {
+		Weight::from_parts(27_000_000, 7072)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn expire_claims(n: u32, ) -> Weight {
+		Weight::from_parts(6_120_000, 2389)
+			.saturating_add(Weight::from_parts(294_354_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((38_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((47_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 102028).saturating_mul(n.into()))
+	}
+	fn force_revoke(a: u32, ) -> Weight {
+		Weight::from_parts(51_000_000, 29408)
+			.saturating_add(Weight::from_parts(8_000_000, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes(15_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(0, 2239).saturating_mul(a.into()))
+	}
+	fn force_transfer() -> Weight {
+		Weight::from_parts(64_000_000, 32711)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(14_u64))
+	}
+	fn freeze_claim() -> Weight {
+		Weight::from_parts(19_000_000, 7100)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn unfreeze_claim() -> Weight {
+		Weight::from_parts(16_000_000, 3531)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn set_claim_metadata() -> Weight {
+		Weight::from_parts(44_000_000, 15004)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn clear_claim_metadata() -> Weight {
+		Weight::from_parts(37_000_000, 15004)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn commit_claim() -> Weight {
+		Weight::from_parts(32_000_000, 6299)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn reveal_claim(l: u32, a: u32, ) -> Weight {
+		Weight::from_parts(85_412_587, 44982)
+			.saturating_add(Weight::from_parts(1_902, 0).saturating_mul(l.into()))
+			.saturating_add(Weight::from_parts(8_000_000, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes(16_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(0, 2239).saturating_mul(a.into()))
+	}
+	fn remove_commitment() -> Weight {
+		Weight::from_parts(30_000_000, 6299)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn set_co_owners(n: u32, ) -> Weight {
+		Weight::from_parts(28_104_315, 6698)
+			.saturating_add(Weight::from_parts(612_427, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn propose_revoke(a: u32, ) -> Weight {
+		Weight::from_parts(62_000_000, 35521)
+			.saturating_add(Weight::from_parts(8_000_000, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes(15_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(0, 2239).saturating_mul(a.into()))
+	}
+	fn propose_transfer() -> Weight {
+		Weight::from_parts(78_000_000, 41347)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(14_u64))
+	}
+	fn approve_revoke_proposal(a: u32, ) -> Weight {
+		Weight::from_parts(65_000_000, 35521)
+			.saturating_add(Weight::from_parts(8_000_000, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes(15_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(0, 2239).saturating_mul(a.into()))
+	}
+	fn approve_transfer_proposal() -> Weight {
+		Weight::from_parts(81_000_000, 41347)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(14_u64))
+	}
+	fn cancel_proposal() -> Weight {
+		Weight::from_parts(21_000_000, 3073)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn attest_claim() -> Weight {
+		Weight::from_parts(33_000_000, 12763)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn withdraw_attestation() -> Weight {
+		Weight::from_parts(31_000_000, 10107)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn supersede_claim() -> Weight {
+		Weight::from_parts(54_000_000, 35330)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	fn list_claim() -> Weight {
+		Weight::from_parts(21_000_000, 8152)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn delist_claim() -> Weight {
+		Weight::from_parts(19_000_000, 5113)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn buy_claim() -> Weight {
+		Weight::from_parts(106_000_000, 43416)
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(16_u64))
+	}
+	fn remove_attestation() -> Weight {
+		Weight::from_parts(37_000_000, 15785)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn clear_tombstone() -> Weight {
+		Weight::from_parts(27_000_000, 8460)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn create_claim(l: u32, ) -> Weight {
+		Weight::from_parts(48_884_102, 25963)
+			.saturating_add(Weight::from_parts(1_874, 0).saturating_mul(l.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	fn revoke_claim(a: u32, ) -> Weight {
+		Weight::from_parts(53_000_000, 32448)
+			.saturating_add(Weight::from_parts(8_000_000, 0).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(RocksDbWeight::get().writes(15_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(0, 2239).saturating_mul(a.into()))
+	}
+	fn transfer_claim() -> Weight {
+		Weight::from_parts(68_000_000, 38274)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(14_u64))
+	}
+	fn create_hashed_claim() -> Weight {
+		Weight::from_parts(45_000_000, 24474)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	fn offer_claim() -> Weight {
+		Weight::from_parts(24_000_000, 9140)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn accept_claim() -> Weight {
+		Weight::from_parts(72_000_000, 38793)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(14_u64))
+	}
+	fn cancel_offer() -> Weight {
+		Weight::from_parts(22_000_000, 7152)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn create_batch_root() -> Weight {
+		Weight::from_parts(46_000_000, 24474)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	fn renew_claim() -> Weight {
+		Weight::from_parts(27_000_000, 7072)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn expire_claims(n: u32, ) -> Weight {
+		Weight::from_parts(6_120_000, 2389)
+			.saturating_add(Weight::from_parts(294_354_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((38_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((47_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 102028).saturating_mul(n.into()))
+	}
+	fn force_revoke(a: u32, ) -> Weight {
+		Weight::from_parts(51_000_000, 29408)
+			.saturating_add(Weight::from_parts(8_000_000, 0).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(RocksDbWeight::get().writes(15_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(0, 2239).saturating_mul(a.into()))
+	}
+	fn force_transfer() -> Weight {
+		Weight::from_parts(64_000_000, 32711)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(14_u64))
+	}
+	fn freeze_claim() -> Weight {
+		Weight::from_parts(19_000_000, 7100)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn unfreeze_claim() -> Weight {
+		Weight::from_parts(16_000_000, 3531)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn set_claim_metadata() -> Weight {
+		Weight::from_parts(44_000_000, 15004)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn clear_claim_metadata() -> Weight {
+		Weight::from_parts(37_000_000, 15004)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn commit_claim() -> Weight {
+		Weight::from_parts(32_000_000, 6299)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn reveal_claim(l: u32, a: u32, ) -> Weight {
+		Weight::from_parts(85_412_587, 44982)
+			.saturating_add(Weight::from_parts(1_902, 0).saturating_mul(l.into()))
+			.saturating_add(Weight::from_parts(8_000_000, 0).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(RocksDbWeight::get().writes(16_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(0, 2239).saturating_mul(a.into()))
+	}
+	fn remove_commitment() -> Weight {
+		Weight::from_parts(30_000_000, 6299)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn set_co_owners(n: u32, ) -> Weight {
+		Weight::from_parts(28_104_315, 6698)
+			.saturating_add(Weight::from_parts(612_427, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn propose_revoke(a: u32, ) -> Weight {
+		Weight::from_parts(62_000_000, 35521)
+			.saturating_add(Weight::from_parts(8_000_000, 0).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(RocksDbWeight::get().writes(15_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(0, 2239).saturating_mul(a.into()))
+	}
+	fn propose_transfer() -> Weight {
+		Weight::from_parts(78_000_000, 41347)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(14_u64))
+	}
+	fn approve_revoke_proposal(a: u32, ) -> Weight {
+		Weight::from_parts(65_000_000, 35521)
+			.saturating_add(Weight::from_parts(8_000_000, 0).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(RocksDbWeight::get().writes(15_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(0, 2239).saturating_mul(a.into()))
+	}
+	fn approve_transfer_proposal() -> Weight {
+		Weight::from_parts(81_000_000, 41347)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(14_u64))
+	}
+	fn cancel_proposal() -> Weight {
+		Weight::from_parts(21_000_000, 3073)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn attest_claim() -> Weight {
+		Weight::from_parts(33_000_000, 12763)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn withdraw_attestation() -> Weight {
+		Weight::from_parts(31_000_000, 10107)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn supersede_claim() -> Weight {
+		Weight::from_parts(54_000_000, 35330)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	fn list_claim() -> Weight {
+		Weight::from_parts(21_000_000, 8152)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn delist_claim() -> Weight {
+		Weight::from_parts(19_000_000, 5113)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn buy_claim() -> Weight {
+		Weight::from_parts(106_000_000, 43416)
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(16_u64))
+	}
+	fn remove_attestation() -> Weight {
+		Weight::from_parts(37_000_000, 15785)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn clear_tombstone() -> Weight {
+		Weight::from_parts(27_000_000, 8460)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+}
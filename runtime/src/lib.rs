@@ -13,7 +13,8 @@ use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
 	traits::{
-		AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, One, Verify,
+		AccountIdConversion, AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount,
+		NumberFor, One, Verify,
 	},
 	transaction_validity::{TransactionSource, TransactionValidity},
 	ApplyExtrinsicResult, MultiSignature,
@@ -27,7 +28,8 @@ use sp_version::RuntimeVersion;
 pub use frame_support::{
 	construct_runtime, parameter_types,
 	traits::{
-		ConstU128, ConstU32, ConstU64, ConstU8, KeyOwnerProofSystem, Randomness, StorageInfo,
+		ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, KeyOwnerProofSystem, Randomness,
+		StorageInfo,
 	},
 	weights::{
 		constants::{
@@ -37,7 +39,9 @@ pub use frame_support::{
 	},
 	StorageValue,
 };
+use frame_support::{traits::EitherOf, PalletId};
 pub use frame_system::Call as SystemCall;
+use frame_system::{EnsureRoot, EnsureRootWithSuccess, EnsureSigned};
 pub use pallet_balances::Call as BalancesCall;
 pub use pallet_timestamp::Call as TimestampCall;
 use pallet_transaction_payment::{ConstFeeMultiplier, CurrencyAdapter, Multiplier};
@@ -103,10 +107,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 100,
+	spec_version: 101,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 	state_version: 1,
 };
 
@@ -233,7 +237,7 @@ pub const EXISTENTIAL_DEPOSIT: u128 = 500;
 
 impl pallet_balances::Config for Runtime {
 	type MaxLocks = ConstU32<50>;
-	type MaxReserves = ();
+	type MaxReserves = ConstU32<50>;
 	type ReserveIdentifier = [u8; 8];
 	/// The type for recording an account's balance.
 	type Balance = Balance;
@@ -268,9 +272,111 @@ impl pallet_template::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 }
 
-impl pallet_poe::Config for Runtime{
+parameter_types! {
+	pub const PoeReserveId: [u8; 8] = *b"poe/clam";
+	pub const ClaimDepositBase: Balance = 100 * EXISTENTIAL_DEPOSIT;
+	pub const ClaimDepositPerByte: Balance = EXISTENTIAL_DEPOSIT;
+	pub const MetadataDepositBase: Balance = 100 * EXISTENTIAL_DEPOSIT;
+	pub const MetadataDepositPerByte: Balance = EXISTENTIAL_DEPOSIT;
+	pub const OfferDuration: BlockNumber = 7 * DAYS;
+	pub const MinRevealDelay: BlockNumber = 2;
+	pub const CommitmentLifetime: BlockNumber = DAYS;
+	pub const ProposalDuration: BlockNumber = 7 * DAYS;
+	pub const AttestationDeposit: Balance = 10 * EXISTENTIAL_DEPOSIT;
+	pub const TombstoneDeposit: Balance = 10 * EXISTENTIAL_DEPOSIT;
+	pub const RoyaltyRate: Permill = Permill::from_percent(5);
+	/// The account holding the claims created by root.
+	pub RootClaimOwner: AccountId = PalletId(*b"poe/root").into_account_truncating();
+}
+
+/// The most claims `PoeApi::claims_by_owner` returns in a single page.
+pub const MAX_CLAIMS_PER_PAGE: u32 = 100;
+
+impl pallet_poe::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type MaxClaimLength = ConstU32<512>;
+	type ClaimHasher = BlakeTwo256;
+	type MaxClaimsPerAccount = ConstU32<1_000>;
+	type OfferDuration = OfferDuration;
+	type MinRevealDelay = MinRevealDelay;
+	type CommitmentLifetime = CommitmentLifetime;
+	type MaxHistoryLength = ConstU32<100>;
+	type MaxExpiriesPerBlock = ConstU32<50>;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type ClaimOrigin =
+		EitherOf<EnsureSigned<AccountId>, EnsureRootWithSuccess<AccountId, RootClaimOwner>>;
+	type MaxCoOwners = ConstU32<16>;
+	type ProposalDuration = ProposalDuration;
+	type MaxAttestations = ConstU32<32>;
+	type MaxNoteLength = ConstU32<128>;
+	type AttestationDeposit = AttestationDeposit;
+	type MaxReasonLength = ConstU32<128>;
+	type AllowReregistration = ConstBool<false>;
+	type TombstoneDeposit = TombstoneDeposit;
+	type TimeProvider = Timestamp;
+	type Currency = Balances;
+	type ReserveId = PoeReserveId;
+	type ClaimDepositBase = ClaimDepositBase;
+	type ClaimDepositPerByte = ClaimDepositPerByte;
+	type MaxMimeTypeLength = ConstU32<64>;
+	type MaxTitleLength = ConstU32<128>;
+	type MaxUriLength = ConstU32<256>;
+	type MaxAttributes = ConstU32<16>;
+	type MaxAttributeKeyLength = ConstU32<32>;
+	type MaxAttributeValueLength = ConstU32<128>;
+	type MetadataDepositBase = MetadataDepositBase;
+	type MimeTypeDepositPerByte = MetadataDepositPerByte;
+	type TitleDepositPerByte = MetadataDepositPerByte;
+	type UriDepositPerByte = MetadataDepositPerByte;
+	type AttributeDepositPerByte = MetadataDepositPerByte;
+	type RoyaltyRate = RoyaltyRate;
+	type WeightInfo = pallet_poe::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const ReleaseReserveId: [u8; 8] = *b"poe/rels";
+	pub const ReleaseDepositBase: Balance = 10 * EXISTENTIAL_DEPOSIT;
+}
+
+/// A second registry, kept apart from `Poe`, for release artefact hashes.
+impl pallet_poe::Config<pallet_poe::Instance1> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type MaxClaimLength = ConstU32<64>;
+	type ClaimHasher = BlakeTwo256;
+	type MaxClaimsPerAccount = ConstU32<10_000>;
+	type OfferDuration = OfferDuration;
+	type MinRevealDelay = MinRevealDelay;
+	type CommitmentLifetime = CommitmentLifetime;
+	type MaxHistoryLength = ConstU32<100>;
+	type MaxExpiriesPerBlock = ConstU32<50>;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type ClaimOrigin = EnsureSigned<AccountId>;
+	type MaxCoOwners = ConstU32<16>;
+	type ProposalDuration = ProposalDuration;
+	type MaxAttestations = ConstU32<32>;
+	type MaxNoteLength = ConstU32<128>;
+	type AttestationDeposit = AttestationDeposit;
+	type MaxReasonLength = ConstU32<128>;
+	type AllowReregistration = ConstBool<false>;
+	type TombstoneDeposit = TombstoneDeposit;
+	type TimeProvider = Timestamp;
+	type Currency = Balances;
+	type ReserveId = ReleaseReserveId;
+	type ClaimDepositBase = ReleaseDepositBase;
+	type ClaimDepositPerByte = ClaimDepositPerByte;
+	type MaxMimeTypeLength = ConstU32<64>;
+	type MaxTitleLength = ConstU32<128>;
+	type MaxUriLength = ConstU32<256>;
+	type MaxAttributes = ConstU32<16>;
+	type MaxAttributeKeyLength = ConstU32<32>;
+	type MaxAttributeValueLength = ConstU32<128>;
+	type MetadataDepositBase = MetadataDepositBase;
+	type MimeTypeDepositPerByte = MetadataDepositPerByte;
+	type TitleDepositPerByte = MetadataDepositPerByte;
+	type UriDepositPerByte = MetadataDepositPerByte;
+	type AttributeDepositPerByte = MetadataDepositPerByte;
+	type RoyaltyRate = RoyaltyRate;
+	type WeightInfo = pallet_poe::weights::SubstrateWeight<Runtime>;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -290,7 +396,8 @@ construct_runtime!(
 		Sudo: pallet_sudo,
 		// Include the custom logic from the pallet-template in the runtime.
 		TemplateModule: pallet_template,
-		Poe: pallet_poe
+		Poe: pallet_poe,
+		PoeReleases: pallet_poe::<Instance1>,
 	}
 );
 
@@ -317,6 +424,13 @@ pub type UncheckedExtrinsic =
 	generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
 /// The payload being signed in transactions.
 pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
+/// Storage migrations to run on the next runtime upgrade.
+pub type Migrations = (
+	pallet_poe::migrations::v1::MigrateToV1<Runtime>,
+	pallet_poe::migrations::v2::MigrateToV2<Runtime>,
+	pallet_poe::migrations::v3::MigrateToV3<Runtime>,
+	pallet_poe::migrations::init::InitStorageVersion<Runtime, pallet_poe::Instance1>,
+);
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -324,6 +438,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -338,6 +453,7 @@ mod benches {
 		[pallet_balances, Balances]
 		[pallet_timestamp, Timestamp]
 		[pallet_template, TemplateModule]
+		[pallet_poe, Poe]
 	);
 }
 
@@ -501,6 +617,40 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_poe_runtime_api::PoeApi<Block, AccountId, BlockNumber, Hash> for Runtime {
+		fn get_claim(claim: Hash) -> Option<pallet_poe::ClaimDetails<AccountId, BlockNumber>> {
+			Poe::claim_details(claim)
+		}
+
+		fn claims_by_owner(owner: AccountId, start_after: Option<Hash>, limit: u32) -> Vec<Hash> {
+			Poe::claims_of(&owner, start_after, limit.min(MAX_CLAIMS_PER_PAGE))
+		}
+
+		fn claim_count(owner: AccountId) -> u32 {
+			Poe::claim_count(owner)
+		}
+
+		fn claim_history(claim: Hash) -> Vec<pallet_poe::CustodyRecord<AccountId, BlockNumber>> {
+			Poe::claim_history(claim)
+		}
+
+		fn get_revocation(claim: Hash) -> Option<pallet_poe::Revocation<AccountId, BlockNumber>> {
+			Poe::revocation_of(claim)
+		}
+
+		fn claim_revisions(claim: Hash) -> Vec<Hash> {
+			Poe::revision_chain(claim)
+		}
+
+		fn claim_attestations(claim: Hash) -> Vec<pallet_poe::Attestation<AccountId, BlockNumber>> {
+			Poe::claim_attestations(claim)
+		}
+
+		fn verify_inclusion(root: Hash, leaf: Hash, proof: Vec<Hash>) -> bool {
+			Poe::verify_inclusion(root, leaf, &proof)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (